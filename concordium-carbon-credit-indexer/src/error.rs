@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// Errors of the indexer.
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error("Failed to read fixture: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse fixture: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
+    #[error("Event is not a valid hex string: {0}")]
+    Hex(#[from] hex::FromHexError),
+    #[error("Failed to decode event of contract {0}")]
+    InvalidEvent(String),
+    #[error("Amount {0} is too large to be stored")]
+    AmountOverflow(u64),
+}
+
+pub type IndexerResult<A> = Result<A, IndexerError>;
@@ -0,0 +1,111 @@
+//! Recorded blocks and transactions which the indexer is fed with.
+//!
+//! A fixture is a JSON array of blocks. Only the effects on smart contracts
+//! are recorded for each transaction, in the order they happened.
+//! ```json
+//! [{
+//!     "blockHash": "...",
+//!     "blockHeight": 100,
+//!     "blockSlotTime": "2023-08-15T10:00:00Z",
+//!     "transactions": [{
+//!         "hash": "...",
+//!         "sender": "...",
+//!         "effects": [{
+//!             "type": "updated",
+//!             "address": { "index": 1, "subindex": 0 },
+//!             "receiveName": "project_token.mint",
+//!             "amount": "0",
+//!             "events": ["fe00..."]
+//!         }]
+//!     }]
+//! }]
+//! ```
+
+use std::{fs, path::Path};
+
+use concordium_std::ContractAddress;
+use serde::{Deserialize, Deserializer};
+
+use crate::error::IndexerResult;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    pub block_hash: String,
+    pub block_height: u64,
+    pub block_slot_time: String,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub hash: String,
+    pub sender: Option<String>,
+    pub effects: Vec<ContractEffect>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct FixtureContractAddress {
+    pub index: u64,
+    pub subindex: u64,
+}
+
+impl From<FixtureContractAddress> for ContractAddress {
+    fn from(address: FixtureContractAddress) -> Self {
+        ContractAddress::new(address.index, address.subindex)
+    }
+}
+
+/// Effect of a transaction on a smart contract.
+/// Events are the hex encoded logs of the contract.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ContractEffect {
+    #[serde(rename_all = "camelCase")]
+    Initialized {
+        address: FixtureContractAddress,
+        init_name: String,
+        events: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Updated {
+        address: FixtureContractAddress,
+        receive_name: String,
+        /// Amount of micro CCD sent to the contract.
+        #[serde(default, deserialize_with = "micro_ccd")]
+        amount: u64,
+        events: Vec<String>,
+    },
+    /// The contract called another contract. Events logged before the call.
+    Interrupted {
+        address: FixtureContractAddress,
+        events: Vec<String>,
+    },
+    /// Any other effect, e.g. the contract resuming or transferring CCD.
+    #[serde(other)]
+    Other,
+}
+
+impl ContractEffect {
+    /// Name of the contract, if it is part of the effect.
+    pub fn contract_name(&self) -> Option<&str> {
+        match self {
+            ContractEffect::Initialized { init_name, .. } => init_name.strip_prefix("init_"),
+            ContractEffect::Updated { receive_name, .. } => receive_name.split('.').next(),
+            _ => None,
+        }
+    }
+}
+
+/// Amounts are recorded as a string of micro CCD.
+fn micro_ccd<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let amount = String::deserialize(deserializer)?;
+    amount.parse().map_err(serde::de::Error::custom)
+}
+
+/// Reads the blocks of a fixture file.
+pub fn read_blocks(path: &Path) -> IndexerResult<Vec<Block>> {
+    let fixture = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&fixture)?)
+}
@@ -0,0 +1,795 @@
+//! Indexing of recorded blocks into the store.
+
+use std::fmt::Display;
+
+use carbon_credit_module::{
+    carbon_credit_market, carbon_credits,
+    client_utils::{
+        roles::Role,
+        types::{Beneficiary, RetirementCertificate},
+    },
+    decode::{decode_event, ContractEvent, DecodeError},
+    project_token,
+};
+use concordium_cis2::{IsTokenAmount, IsTokenId};
+use concordium_std::{Address, ContractAddress};
+use rusqlite::{params, OptionalExtension, Transaction as DbTransaction};
+
+use crate::{
+    error::{IndexerError, IndexerResult},
+    fixtures::{Block, ContractEffect, Transaction},
+    store::Store,
+};
+
+/// Context of an event which is being indexed.
+struct EventContext<'a> {
+    block: &'a Block,
+    transaction_hash: &'a str,
+    contract: ContractAddress,
+}
+
+impl Store {
+    /// Indexes the events of all the blocks.
+    /// Returns the number of blocks which were not indexed before.
+    pub fn index_blocks(&mut self, blocks: &[Block]) -> IndexerResult<usize> {
+        let mut indexed = 0;
+        for block in blocks {
+            if self.index_block(block)? {
+                indexed += 1;
+            }
+        }
+
+        Ok(indexed)
+    }
+
+    /// Indexes the events of a block.
+    /// Blocks which are already indexed are skipped and `false` is returned.
+    pub fn index_block(&mut self, block: &Block) -> IndexerResult<bool> {
+        let tx = self.conn.transaction()?;
+        let is_indexed = tx
+            .query_row(
+                "SELECT 1 FROM blocks WHERE block_hash = ?1",
+                params![block.block_hash],
+                |_| Ok(()),
+            )
+            .optional()?
+            .is_some();
+        if is_indexed {
+            return Ok(false);
+        }
+
+        tx.execute(
+            "INSERT INTO blocks (block_hash, block_height, block_slot_time) VALUES (?1, ?2, ?3)",
+            params![block.block_hash, block.block_height, block.block_slot_time],
+        )?;
+        for transaction in &block.transactions {
+            index_transaction(&tx, block, transaction)?;
+        }
+        tx.commit()?;
+
+        Ok(true)
+    }
+}
+
+fn index_transaction(
+    tx: &DbTransaction,
+    block: &Block,
+    transaction: &Transaction,
+) -> IndexerResult<()> {
+    for effect in &transaction.effects {
+        let (address, events) = match effect {
+            ContractEffect::Initialized {
+                address, events, ..
+            }
+            | ContractEffect::Updated {
+                address, events, ..
+            }
+            | ContractEffect::Interrupted { address, events } => (address, events),
+            ContractEffect::Other => continue,
+        };
+        let contract = ContractAddress::from(*address);
+        if let Some(name) = effect.contract_name() {
+            tx.execute(
+                "INSERT OR REPLACE INTO contracts (contract_index, contract_subindex, name)
+                VALUES (?1, ?2, ?3)",
+                params![contract.index, contract.subindex, name],
+            )?;
+        }
+        // Contracts are known once they are initialized or updated.
+        let name: Option<String> = tx
+            .query_row(
+                "SELECT name FROM contracts WHERE contract_index = ?1 AND contract_subindex = ?2",
+                params![contract.index, contract.subindex],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let name = match name {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let ctx = EventContext {
+            block,
+            transaction_hash: &transaction.hash,
+            contract,
+        };
+        // Collateral used to mint the next Carbon Credit token.
+        let mut collateral = None;
+        for event in events {
+            let bytes = hex::decode(event)?;
+            let event = match decode_event(&name, &bytes) {
+                Ok(event) => event,
+                // Events of other contracts are not indexed.
+                Err(DecodeError::UnknownContract) => continue,
+                Err(DecodeError::InvalidEvent) => return Err(IndexerError::InvalidEvent(name)),
+            };
+            tx.execute(
+                "INSERT INTO events (block_height, transaction_hash, contract_index,
+                contract_subindex, contract_name, tag, event)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    block.block_height,
+                    transaction.hash,
+                    contract.index,
+                    contract.subindex,
+                    name,
+                    bytes[0],
+                    hex::encode(&bytes)
+                ],
+            )?;
+
+            match event {
+                ContractEvent::ProjectToken(event) => index_project_token_event(tx, &ctx, event)?,
+                ContractEvent::CarbonCredits(event) => {
+                    index_carbon_credits_event(tx, &ctx, event, &mut collateral)?
+                }
+                ContractEvent::CarbonCreditMarket(event) => {
+                    index_carbon_credit_market_event(tx, &ctx, event)?
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn index_project_token_event(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    event: project_token::events::ContractEvent,
+) -> IndexerResult<()> {
+    use project_token::events::ContractEvent;
+
+    match event {
+        ContractEvent::Mint(event) => {
+            mint(
+                tx,
+                ctx,
+                &event.token_id,
+                &event.owner,
+                event.amount.0.into(),
+            )?;
+            tx.execute(
+                "INSERT OR IGNORE INTO projects (contract_index, contract_subindex, token_id)
+                VALUES (?1, ?2, ?3)",
+                params![
+                    ctx.contract.index,
+                    ctx.contract.subindex,
+                    event.token_id.to_string()
+                ],
+            )?;
+        }
+        ContractEvent::TokenMetadata(event) => {
+            token_metadata(tx, ctx, &event.token_id, &event.metadata_url)?
+        }
+        ContractEvent::MaturityTime(event) => maturity_schedule(tx, ctx, &event)?,
+        ContractEvent::MaturityAmended(event) => {
+            tx.execute(
+                "INSERT INTO maturity_amendments (contract_index, contract_subindex, token_id,
+                verifier, reason, block_height, transaction_hash)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    ctx.contract.index,
+                    ctx.contract.subindex,
+                    event.token_id.to_string(),
+                    event.verifier.to_string(),
+                    event.reason,
+                    ctx.block.block_height,
+                    ctx.transaction_hash
+                ],
+            )?;
+        }
+        ContractEvent::QuorumUpdated(event) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO quorums (contract_index, contract_subindex, threshold)
+                VALUES (?1, ?2, ?3)",
+                params![ctx.contract.index, ctx.contract.subindex, event.threshold],
+            )?;
+            for weight in event.weights {
+                tx.execute(
+                    "INSERT OR REPLACE INTO verifier_weights (contract_index, contract_subindex,
+                    verifier, weight) VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        ctx.contract.index,
+                        ctx.contract.subindex,
+                        weight.verifier.to_string(),
+                        weight.weight
+                    ],
+                )?;
+            }
+        }
+        ContractEvent::Transfer(event) => transfer(
+            tx,
+            ctx,
+            &event.token_id,
+            &event.from,
+            &event.to,
+            event.amount.0.into(),
+        )?,
+        ContractEvent::Burn(event) => burn(
+            tx,
+            ctx,
+            &event.token_id,
+            &event.owner,
+            event.amount.0.into(),
+        )?,
+        ContractEvent::Retire(event) => retire(
+            tx,
+            ctx,
+            &event.token_id,
+            &event.owner,
+            event.amount.0.into(),
+        )?,
+        ContractEvent::VerifierAdded(event) => verifier_updated(tx, ctx, &event.verifier, true)?,
+        ContractEvent::VerifierRemoved(event) => verifier_updated(tx, ctx, &event.verifier, false)?,
+        ContractEvent::RoleGranted(event) if event.role == Role::Verifier => {
+            verifier_updated(tx, ctx, &event.address, true)?
+        }
+        ContractEvent::RoleRevoked(event) if event.role == Role::Verifier => {
+            verifier_updated(tx, ctx, &event.address, false)?
+        }
+        ContractEvent::Verification(event) => {
+            tx.execute(
+                "INSERT INTO verifications (contract_index, contract_subindex, token_id,
+                verifier, block_height) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    ctx.contract.index,
+                    ctx.contract.subindex,
+                    event.token_id.to_string(),
+                    event.verifier.to_string(),
+                    ctx.block.block_height
+                ],
+            )?;
+        }
+        ContractEvent::MetadataFrozen(event) => {
+            tx.execute(
+                "UPDATE projects SET metadata_frozen = 1
+                WHERE contract_index = ?1 AND contract_subindex = ?2 AND token_id = ?3",
+                params![
+                    ctx.contract.index,
+                    ctx.contract.subindex,
+                    event.token_id.to_string()
+                ],
+            )?;
+        }
+        ContractEvent::VerificationRevoked(event) => {
+            remove_verification(tx, ctx, &event.token_id, &event.verifier)?
+        }
+        ContractEvent::VerificationsAffected(event) if event.invalidated => {
+            for token_id in event.token_ids {
+                remove_verification(tx, ctx, &token_id, &event.verifier)?;
+            }
+        }
+        ContractEvent::RetirementCertificate(event) => retirement_certificate(tx, ctx, &event)?,
+        // Retracted tokens are also logged as burnt.
+        ContractEvent::Retract(_)
+        | ContractEvent::UpdateOperator(_)
+        | ContractEvent::VerificationsAffected(_)
+        | ContractEvent::RoleGranted(_)
+        | ContractEvent::RoleRevoked(_)
+        | ContractEvent::AdminProposed(_)
+        | ContractEvent::AdminTransferred(_)
+        | ContractEvent::Paused(_)
+        | ContractEvent::Unpaused(_) => {}
+    }
+
+    Ok(())
+}
+
+fn index_carbon_credits_event(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    event: carbon_credits::events::ContractEvent,
+    collateral: &mut Option<carbon_credits::events::CollateralUpdatedEvent>,
+) -> IndexerResult<()> {
+    use carbon_credits::events::ContractEvent;
+
+    match event {
+        ContractEvent::CollateralUsedEvent(event) => *collateral = Some(event),
+        ContractEvent::Mint(event) => {
+            mint(tx, ctx, &event.token_id, &event.owner, event.amount.0)?;
+            let collateral = collateral.take();
+            tx.execute(
+                "INSERT OR IGNORE INTO credits (contract_index, contract_subindex, token_id,
+                project_contract_index, project_contract_subindex, project_token_id)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    ctx.contract.index,
+                    ctx.contract.subindex,
+                    event.token_id.to_string(),
+                    collateral.as_ref().map(|c| c.contract.index),
+                    collateral.as_ref().map(|c| c.contract.subindex),
+                    collateral.as_ref().map(|c| c.token_id.to_string())
+                ],
+            )?;
+        }
+        ContractEvent::TokenMetadata(event) => {
+            token_metadata(tx, ctx, &event.token_id, &event.metadata_url)?
+        }
+        ContractEvent::Transfer(event) => transfer(
+            tx,
+            ctx,
+            &event.token_id,
+            &event.from,
+            &event.to,
+            event.amount.0,
+        )?,
+        ContractEvent::Burn(event) => burn(tx, ctx, &event.token_id, &event.owner, event.amount.0)?,
+        ContractEvent::Retire(event) => {
+            retire(tx, ctx, &event.token_id, &event.owner, event.amount.0)?
+        }
+        ContractEvent::RetirementCertificate(event) => retirement_certificate(tx, ctx, &event)?,
+        // Retracted tokens are also logged as burnt.
+        ContractEvent::Retract(_)
+        | ContractEvent::UpdateOperator(_)
+        | ContractEvent::CollateralAdded(_)
+        | ContractEvent::CollateralRemoved(_)
+        | ContractEvent::RoleGranted(_)
+        | ContractEvent::RoleRevoked(_)
+        | ContractEvent::AdminProposed(_)
+        | ContractEvent::AdminTransferred(_)
+        | ContractEvent::Paused(_)
+        | ContractEvent::Unpaused(_)
+        | ContractEvent::VerifierContractAdded(_)
+        | ContractEvent::VerifierContractRemoved(_) => {}
+    }
+
+    Ok(())
+}
+
+fn index_carbon_credit_market_event(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    event: carbon_credit_market::events::ContractEvent,
+) -> IndexerResult<()> {
+    use carbon_credit_market::events::ContractEvent;
+
+    if let ContractEvent::TokenSold(event) = event {
+        tx.execute(
+            "INSERT INTO sales (market_index, market_subindex, token_contract_index,
+            token_contract_subindex, token_id, seller, buyer, amount, paid, block_height,
+            block_slot_time, transaction_hash)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            params![
+                ctx.contract.index,
+                ctx.contract.subindex,
+                event.token_contract.index,
+                event.token_contract.subindex,
+                event.token_id.to_string(),
+                event.seller.to_string(),
+                event.buyer.to_string(),
+                to_sql_amount(event.amount.0)?,
+                // The seller and the royalty are paid what is left after the commission.
+                to_sql_amount(event.paid.micro_ccd - event.commission.micro_ccd)?,
+                ctx.block.block_height,
+                ctx.block.block_slot_time,
+                ctx.transaction_hash
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn mint(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    token_id: &impl Display,
+    owner: &Address,
+    amount: u64,
+) -> IndexerResult<()> {
+    tx.execute(
+        "INSERT INTO tokens (contract_index, contract_subindex, token_id, supply, block_height)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT (contract_index, contract_subindex, token_id)
+        DO UPDATE SET supply = supply + excluded.supply",
+        params![
+            ctx.contract.index,
+            ctx.contract.subindex,
+            token_id.to_string(),
+            to_sql_amount(amount)?,
+            ctx.block.block_height
+        ],
+    )?;
+    update_balance(tx, ctx, token_id, owner, to_sql_amount(amount)?)
+}
+
+fn burn(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    token_id: &impl Display,
+    owner: &Address,
+    amount: u64,
+) -> IndexerResult<()> {
+    tx.execute(
+        "UPDATE tokens SET supply = supply - ?1
+        WHERE contract_index = ?2 AND contract_subindex = ?3 AND token_id = ?4",
+        params![
+            to_sql_amount(amount)?,
+            ctx.contract.index,
+            ctx.contract.subindex,
+            token_id.to_string()
+        ],
+    )?;
+    update_balance(tx, ctx, token_id, owner, -to_sql_amount(amount)?)
+}
+
+fn transfer(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    token_id: &impl Display,
+    from: &Address,
+    to: &Address,
+    amount: u64,
+) -> IndexerResult<()> {
+    update_balance(tx, ctx, token_id, from, -to_sql_amount(amount)?)?;
+    update_balance(tx, ctx, token_id, to, to_sql_amount(amount)?)
+}
+
+fn retire(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    token_id: &impl Display,
+    owner: &Address,
+    amount: u64,
+) -> IndexerResult<()> {
+    tx.execute(
+        "INSERT INTO retirements (contract_index, contract_subindex, token_id, owner, amount,
+        block_height, block_slot_time, transaction_hash)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            ctx.contract.index,
+            ctx.contract.subindex,
+            token_id.to_string(),
+            owner.to_string(),
+            to_sql_amount(amount)?,
+            ctx.block.block_height,
+            ctx.block.block_slot_time,
+            ctx.transaction_hash
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Replaces the maturity schedule of a project token.
+fn maturity_schedule(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    event: &project_token::events::MaturityTimeEvent,
+) -> IndexerResult<()> {
+    let token_id = event.token_id.to_string();
+    tx.execute(
+        "DELETE FROM maturity_tranches
+        WHERE contract_index = ?1 AND contract_subindex = ?2 AND token_id = ?3",
+        params![ctx.contract.index, ctx.contract.subindex, token_id],
+    )?;
+    for tranche in &event.maturity.tranches {
+        tx.execute(
+            "INSERT INTO maturity_tranches (contract_index, contract_subindex, token_id, time,
+            fraction) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                ctx.contract.index,
+                ctx.contract.subindex,
+                token_id,
+                tranche.time.timestamp_millis(),
+                tranche.fraction
+            ],
+        )?;
+    }
+    tx.execute(
+        "UPDATE projects SET maturity_time = ?1
+        WHERE contract_index = ?2 AND contract_subindex = ?3 AND token_id = ?4",
+        params![
+            event
+                .maturity
+                .maturity_time()
+                .map(|time| time.timestamp_millis()),
+            ctx.contract.index,
+            ctx.contract.subindex,
+            token_id
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn remove_verification(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    token_id: &impl Display,
+    verifier: &Address,
+) -> IndexerResult<()> {
+    tx.execute(
+        "DELETE FROM verifications
+        WHERE contract_index = ?1 AND contract_subindex = ?2 AND token_id = ?3
+        AND verifier = ?4",
+        params![
+            ctx.contract.index,
+            ctx.contract.subindex,
+            token_id.to_string(),
+            verifier.to_string()
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Adds the certificate to the retirement it was logged after.
+fn retirement_certificate<T: IsTokenId + Display, A: IsTokenAmount>(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    certificate: &RetirementCertificate<T, A>,
+) -> IndexerResult<()> {
+    let details = &certificate.details;
+    let beneficiary = match &details.beneficiary {
+        Beneficiary::Name(name) => name.clone(),
+        Beneficiary::Address(address) => address.to_string(),
+    };
+    // The certificate of every retirement is logged right after it, so the
+    // first retirement of the transaction without a certificate is the one.
+    tx.execute(
+        "UPDATE retirements SET certificate_id = ?1, beneficiary = ?2, reason = ?3,
+        reporting_period_start = ?4, reporting_period_end = ?5
+        WHERE id = (SELECT MIN(id) FROM retirements
+            WHERE contract_index = ?6 AND contract_subindex = ?7 AND token_id = ?8
+            AND owner = ?9 AND transaction_hash = ?10 AND certificate_id IS NULL)",
+        params![
+            certificate.certificate_id,
+            beneficiary,
+            details.reason,
+            details.reporting_period.start.timestamp_millis(),
+            details.reporting_period.end.timestamp_millis(),
+            ctx.contract.index,
+            ctx.contract.subindex,
+            certificate.token_id.to_string(),
+            certificate.owner.to_string(),
+            ctx.transaction_hash
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn token_metadata(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    token_id: &impl Display,
+    metadata_url: &concordium_cis2::MetadataUrl,
+) -> IndexerResult<()> {
+    tx.execute(
+        "UPDATE tokens SET metadata_url = ?1, metadata_hash = ?2
+        WHERE contract_index = ?3 AND contract_subindex = ?4 AND token_id = ?5",
+        params![
+            metadata_url.url,
+            metadata_url.hash.map(hex::encode),
+            ctx.contract.index,
+            ctx.contract.subindex,
+            token_id.to_string()
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn verifier_updated(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    verifier: &Address,
+    active: bool,
+) -> IndexerResult<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO verifiers (contract_index, contract_subindex, verifier, active)
+        VALUES (?1, ?2, ?3, ?4)",
+        params![
+            ctx.contract.index,
+            ctx.contract.subindex,
+            verifier.to_string(),
+            active
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn update_balance(
+    tx: &DbTransaction,
+    ctx: &EventContext,
+    token_id: &impl Display,
+    owner: &Address,
+    delta: i64,
+) -> IndexerResult<()> {
+    tx.execute(
+        "INSERT INTO balances (contract_index, contract_subindex, token_id, owner, amount)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT (contract_index, contract_subindex, token_id, owner)
+        DO UPDATE SET amount = amount + excluded.amount",
+        params![
+            ctx.contract.index,
+            ctx.contract.subindex,
+            token_id.to_string(),
+            owner.to_string(),
+            delta
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn to_sql_amount(amount: u64) -> IndexerResult<i64> {
+    i64::try_from(amount).map_err(|_| IndexerError::AmountOverflow(amount))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::{fixtures::read_blocks, queries::*, store::Store};
+
+    const ACCOUNT_0: &str = "2xBpaHottqhwFZURMZW4uZduQvpxNDSy46iXMYs9kceNGaPpZX";
+    const ACCOUNT_1: &str = "2xdTv8awN1BjgYEw8W1BVXVtiEwG2b29U8KoZQqJrDuEqddseE";
+    const VERIFIER: &str = "2y57FyMyqAfY7X1SuSWJ5VMt1Z3ZgxbKt9w5mGoTwqA7YcpbXr";
+
+    fn indexed_store() -> Store {
+        let blocks = read_blocks(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/carbon_credits.json"),
+        )
+        .expect("fixture should be readable");
+        let mut store = Store::open_in_memory().unwrap();
+        assert_eq!(store.index_blocks(&blocks).unwrap(), blocks.len());
+        // Blocks are only indexed once.
+        assert_eq!(store.index_blocks(&blocks).unwrap(), 0);
+        store
+    }
+
+    #[test]
+    fn should_index_projects() {
+        let store = indexed_store();
+
+        assert_eq!(
+            store.projects().unwrap(),
+            vec![Project {
+                contract_index: 1,
+                contract_subindex: 0,
+                token_id: "01000000".to_string(),
+                supply: 1,
+                metadata_url: Some("https://example.com/project/1.json".to_string()),
+                metadata_hash: Some("ab".repeat(32)),
+                maturity_time: Some(1723716000000),
+                maturity: vec![
+                    MaturityTranche {
+                        time: 1692093600000,
+                        fraction: 5000,
+                    },
+                    MaturityTranche {
+                        time: 1723716000000,
+                        fraction: 5000,
+                    },
+                ],
+                maturity_amendments: vec![MaturityAmendment {
+                    verifier: VERIFIER.to_string(),
+                    reason: "Second monitoring period delayed".to_string(),
+                    block_height: 106,
+                    transaction_hash: "0d".repeat(32),
+                }],
+                metadata_frozen: true,
+                verified_by: vec![VERIFIER.to_string()],
+            }]
+        );
+        // The project token is held by the Carbon Credits contract as collateral.
+        assert_eq!(store.balance_of(1, 0, "01000000", ACCOUNT_0).unwrap(), 0);
+        assert_eq!(store.balance_of(1, 0, "01000000", "<2,0>").unwrap(), 1);
+    }
+
+    #[test]
+    fn should_index_quorums() {
+        let store = indexed_store();
+
+        assert_eq!(
+            store.quorums().unwrap(),
+            vec![Quorum {
+                contract_index: 1,
+                contract_subindex: 0,
+                threshold: 2,
+                verifier_weights: vec![VerifierWeight {
+                    verifier: VERIFIER.to_string(),
+                    weight: 2,
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn should_index_credits() {
+        let store = indexed_store();
+
+        assert_eq!(
+            store.credits().unwrap(),
+            vec![Credit {
+                contract_index: 2,
+                contract_subindex: 0,
+                token_id: "01000000".to_string(),
+                supply: 96,
+                metadata_url: Some("https://example.com/credit/1.json".to_string()),
+                metadata_hash: None,
+                project_contract_index: Some(1),
+                project_contract_subindex: Some(0),
+                project_token_id: Some("01000000".to_string()),
+            }]
+        );
+        assert_eq!(store.balance_of(2, 0, "01000000", ACCOUNT_0).unwrap(), 0);
+        assert_eq!(store.balance_of(2, 0, "01000000", "<3,0>").unwrap(), 90);
+        assert_eq!(store.balance_of(2, 0, "01000000", ACCOUNT_1).unwrap(), 6);
+    }
+
+    #[test]
+    fn should_index_retirements() {
+        let store = indexed_store();
+
+        let retirements = store.retirements(None).unwrap();
+        assert_eq!(
+            retirements,
+            vec![Retirement {
+                contract_index: 2,
+                contract_subindex: 0,
+                contract_name: "carbon_credits".to_string(),
+                token_id: "01000000".to_string(),
+                owner: ACCOUNT_1.to_string(),
+                amount: 4,
+                block_height: 105,
+                block_slot_time: "2023-08-15T10:05:00Z".to_string(),
+                transaction_hash: "0c".repeat(32),
+                certificate_id: Some(0),
+                beneficiary: Some("ACME Ltd".to_string()),
+                reason: Some("Offsetting 2023 business travel".to_string()),
+                reporting_period_start: Some(1672531200000),
+                reporting_period_end: Some(1703980800000),
+            }]
+        );
+        assert_eq!(store.retirements(Some(ACCOUNT_1)).unwrap(), retirements);
+        assert!(store.retirements(Some(ACCOUNT_0)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_index_sales() {
+        let store = indexed_store();
+
+        assert_eq!(
+            store.sales().unwrap(),
+            vec![Sale {
+                market_index: 3,
+                market_subindex: 0,
+                token_contract_index: 2,
+                token_contract_subindex: 0,
+                token_id: "01000000".to_string(),
+                seller: ACCOUNT_0.to_string(),
+                buyer: ACCOUNT_1.to_string(),
+                amount: 10,
+                paid: 9_750_000,
+                block_height: 104,
+                block_slot_time: "2023-08-15T10:04:00Z".to_string(),
+                transaction_hash: "0b".repeat(32),
+            }]
+        );
+    }
+}
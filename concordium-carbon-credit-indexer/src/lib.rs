@@ -0,0 +1,11 @@
+//! Off-chain indexer of the carbon credit contracts.
+//!
+//! Decodes the events logged by the `project_token`, `carbon_credits` and
+//! `carbon_credit_market` contracts from recorded blocks and keeps them in a
+//! relational store which can be queried for projects, credits, retirements
+//! and sales.
+pub mod error;
+pub mod fixtures;
+pub mod indexer;
+pub mod queries;
+pub mod store;
@@ -0,0 +1,57 @@
+use std::{env, path::Path, process};
+
+use concordium_carbon_credit_indexer::{error::IndexerResult, fixtures::read_blocks, store::Store};
+
+const USAGE: &str = "Usage:
+    concordium-carbon-credit-indexer <database> index <fixture>...
+    concordium-carbon-credit-indexer <database> projects|credits|sales|quorums
+    concordium-carbon-credit-indexer <database> retirements [owner]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (database, command, rest) = match args.as_slice() {
+        [database, command, rest @ ..] => (database, command.as_str(), rest),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = run(Path::new(database), command, rest) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run(database: &Path, command: &str, args: &[String]) -> IndexerResult<()> {
+    let mut store = Store::open(database)?;
+    let output = match command {
+        "index" => {
+            for fixture in args {
+                let blocks = read_blocks(Path::new(fixture))?;
+                let indexed = store.index_blocks(&blocks)?;
+                println!(
+                    "{}: indexed {} of {} blocks",
+                    fixture,
+                    indexed,
+                    blocks.len()
+                );
+            }
+            return Ok(());
+        }
+        "projects" => serde_json::to_string_pretty(&store.projects()?)?,
+        "credits" => serde_json::to_string_pretty(&store.credits()?)?,
+        "retirements" => {
+            serde_json::to_string_pretty(&store.retirements(args.first().map(String::as_str))?)?
+        }
+        "sales" => serde_json::to_string_pretty(&store.sales()?)?,
+        "quorums" => serde_json::to_string_pretty(&store.quorums()?)?,
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    println!("{}", output);
+
+    Ok(())
+}
@@ -0,0 +1,392 @@
+//! Queries of the indexed projects, credits, retirements and sales.
+
+use rusqlite::{params, OptionalExtension};
+use serde::Serialize;
+
+use crate::{error::IndexerResult, store::Store};
+
+/// A token of the Project Token contract.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Project {
+    pub contract_index: u64,
+    pub contract_subindex: u64,
+    pub token_id: String,
+    pub supply: u64,
+    pub metadata_url: Option<String>,
+    pub metadata_hash: Option<String>,
+    /// Time at which all of the credits of the project have matured, in
+    /// milliseconds since the Unix epoch.
+    pub maturity_time: Option<u64>,
+    /// Tranches of the maturity schedule, in the order they mature.
+    pub maturity: Vec<MaturityTranche>,
+    /// Amendments of the maturity schedule by verifiers, oldest first.
+    pub maturity_amendments: Vec<MaturityAmendment>,
+    /// Whether the metadata can no longer be updated.
+    pub metadata_frozen: bool,
+    /// Verifiers which verified the project.
+    pub verified_by: Vec<String>,
+}
+
+/// Part of the credits of a project which matures at a time.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct MaturityTranche {
+    /// Milliseconds since the Unix epoch.
+    pub time: u64,
+    /// Fraction of the credits in basis points.
+    pub fraction: u16,
+}
+
+/// Amendment of the maturity schedule of a project by a verifier.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct MaturityAmendment {
+    pub verifier: String,
+    pub reason: String,
+    pub block_height: u64,
+    pub transaction_hash: String,
+}
+
+/// Quorum of verifiers needed for a project of the Project Token contract to be
+/// verified.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Quorum {
+    pub contract_index: u64,
+    pub contract_subindex: u64,
+    /// Total weight of the verifications needed.
+    pub threshold: u32,
+    pub verifier_weights: Vec<VerifierWeight>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct VerifierWeight {
+    pub verifier: String,
+    pub weight: u32,
+}
+
+/// A token of the Carbon Credits contract.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Credit {
+    pub contract_index: u64,
+    pub contract_subindex: u64,
+    pub token_id: String,
+    pub supply: u64,
+    pub metadata_url: Option<String>,
+    pub metadata_hash: Option<String>,
+    /// Project token used as collateral to mint the credit.
+    pub project_contract_index: Option<u64>,
+    pub project_contract_subindex: Option<u64>,
+    pub project_token_id: Option<String>,
+}
+
+/// Tokens retired by an owner, in either the Project Token or the Carbon
+/// Credits contract.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Retirement {
+    pub contract_index: u64,
+    pub contract_subindex: u64,
+    pub contract_name: String,
+    pub token_id: String,
+    pub owner: String,
+    pub amount: u64,
+    pub block_height: u64,
+    pub block_slot_time: String,
+    pub transaction_hash: String,
+    /// Certificate of the retirement, if it was logged.
+    pub certificate_id: Option<u64>,
+    /// Name or address of the beneficiary.
+    pub beneficiary: Option<String>,
+    pub reason: Option<String>,
+    /// Milliseconds since the Unix epoch.
+    pub reporting_period_start: Option<u64>,
+    pub reporting_period_end: Option<u64>,
+}
+
+/// Tokens bought from a listing of the Marketplace.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Sale {
+    pub market_index: u64,
+    pub market_subindex: u64,
+    pub token_contract_index: u64,
+    pub token_contract_subindex: u64,
+    pub token_id: String,
+    pub seller: String,
+    pub buyer: String,
+    pub amount: u64,
+    /// Micro CCD paid by the buyer, without the commission of the Marketplace.
+    pub paid: u64,
+    pub block_height: u64,
+    pub block_slot_time: String,
+    pub transaction_hash: String,
+}
+
+impl Store {
+    /// Gets all the indexed projects.
+    pub fn projects(&self) -> IndexerResult<Vec<Project>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT p.contract_index, p.contract_subindex, p.token_id, t.supply, t.metadata_url,
+            t.metadata_hash, p.maturity_time, p.metadata_frozen
+            FROM projects p JOIN tokens t USING (contract_index, contract_subindex, token_id)
+            ORDER BY p.contract_index, p.contract_subindex, p.token_id",
+        )?;
+        let projects = stmt
+            .query_map([], |row| {
+                Ok(Project {
+                    contract_index: row.get(0)?,
+                    contract_subindex: row.get(1)?,
+                    token_id: row.get(2)?,
+                    supply: row.get(3)?,
+                    metadata_url: row.get(4)?,
+                    metadata_hash: row.get(5)?,
+                    maturity_time: row.get(6)?,
+                    metadata_frozen: row.get(7)?,
+                    maturity: vec![],
+                    maturity_amendments: vec![],
+                    verified_by: vec![],
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        projects
+            .into_iter()
+            .map(|mut project| {
+                project.maturity = self.maturity_of(&project)?;
+                project.maturity_amendments = self.maturity_amendments_of(&project)?;
+                project.verified_by = self.verifications_of(&project)?;
+                Ok(project)
+            })
+            .collect()
+    }
+
+    fn verifications_of(&self, project: &Project) -> IndexerResult<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT verifier FROM verifications
+            WHERE contract_index = ?1 AND contract_subindex = ?2 AND token_id = ?3
+            ORDER BY block_height",
+        )?;
+        let verifiers = stmt
+            .query_map(
+                params![
+                    project.contract_index,
+                    project.contract_subindex,
+                    project.token_id
+                ],
+                |row| row.get(0),
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(verifiers)
+    }
+
+    fn maturity_of(&self, project: &Project) -> IndexerResult<Vec<MaturityTranche>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT time, fraction FROM maturity_tranches
+            WHERE contract_index = ?1 AND contract_subindex = ?2 AND token_id = ?3
+            ORDER BY time",
+        )?;
+        let tranches = stmt
+            .query_map(
+                params![
+                    project.contract_index,
+                    project.contract_subindex,
+                    project.token_id
+                ],
+                |row| {
+                    Ok(MaturityTranche {
+                        time: row.get(0)?,
+                        fraction: row.get(1)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tranches)
+    }
+
+    fn maturity_amendments_of(&self, project: &Project) -> IndexerResult<Vec<MaturityAmendment>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT verifier, reason, block_height, transaction_hash FROM maturity_amendments
+            WHERE contract_index = ?1 AND contract_subindex = ?2 AND token_id = ?3
+            ORDER BY id",
+        )?;
+        let amendments = stmt
+            .query_map(
+                params![
+                    project.contract_index,
+                    project.contract_subindex,
+                    project.token_id
+                ],
+                |row| {
+                    Ok(MaturityAmendment {
+                        verifier: row.get(0)?,
+                        reason: row.get(1)?,
+                        block_height: row.get(2)?,
+                        transaction_hash: row.get(3)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(amendments)
+    }
+
+    /// Gets the quorums of all the Project Token contracts.
+    pub fn quorums(&self) -> IndexerResult<Vec<Quorum>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT contract_index, contract_subindex, threshold FROM quorums
+            ORDER BY contract_index, contract_subindex",
+        )?;
+        let quorums = stmt
+            .query_map([], |row| {
+                Ok(Quorum {
+                    contract_index: row.get(0)?,
+                    contract_subindex: row.get(1)?,
+                    threshold: row.get(2)?,
+                    verifier_weights: vec![],
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        quorums
+            .into_iter()
+            .map(|mut quorum| {
+                quorum.verifier_weights = self.verifier_weights_of(&quorum)?;
+                Ok(quorum)
+            })
+            .collect()
+    }
+
+    fn verifier_weights_of(&self, quorum: &Quorum) -> IndexerResult<Vec<VerifierWeight>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT verifier, weight FROM verifier_weights
+            WHERE contract_index = ?1 AND contract_subindex = ?2
+            ORDER BY verifier",
+        )?;
+        let weights = stmt
+            .query_map(
+                params![quorum.contract_index, quorum.contract_subindex],
+                |row| {
+                    Ok(VerifierWeight {
+                        verifier: row.get(0)?,
+                        weight: row.get(1)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(weights)
+    }
+
+    /// Gets all the indexed carbon credits.
+    pub fn credits(&self) -> IndexerResult<Vec<Credit>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT c.contract_index, c.contract_subindex, c.token_id, t.supply, t.metadata_url,
+            t.metadata_hash, c.project_contract_index, c.project_contract_subindex,
+            c.project_token_id
+            FROM credits c JOIN tokens t USING (contract_index, contract_subindex, token_id)
+            ORDER BY c.contract_index, c.contract_subindex, c.token_id",
+        )?;
+        let credits = stmt
+            .query_map([], |row| {
+                Ok(Credit {
+                    contract_index: row.get(0)?,
+                    contract_subindex: row.get(1)?,
+                    token_id: row.get(2)?,
+                    supply: row.get(3)?,
+                    metadata_url: row.get(4)?,
+                    metadata_hash: row.get(5)?,
+                    project_contract_index: row.get(6)?,
+                    project_contract_subindex: row.get(7)?,
+                    project_token_id: row.get(8)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(credits)
+    }
+
+    /// Gets the retirements of all owners, or of the given owner.
+    pub fn retirements(&self, owner: Option<&str>) -> IndexerResult<Vec<Retirement>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.contract_index, r.contract_subindex, c.name, r.token_id, r.owner,
+            r.amount, r.block_height, r.block_slot_time, r.transaction_hash, r.certificate_id,
+            r.beneficiary, r.reason, r.reporting_period_start, r.reporting_period_end
+            FROM retirements r JOIN contracts c USING (contract_index, contract_subindex)
+            WHERE ?1 IS NULL OR r.owner = ?1
+            ORDER BY r.id",
+        )?;
+        let retirements = stmt
+            .query_map(params![owner], |row| {
+                Ok(Retirement {
+                    contract_index: row.get(0)?,
+                    contract_subindex: row.get(1)?,
+                    contract_name: row.get(2)?,
+                    token_id: row.get(3)?,
+                    owner: row.get(4)?,
+                    amount: row.get(5)?,
+                    block_height: row.get(6)?,
+                    block_slot_time: row.get(7)?,
+                    transaction_hash: row.get(8)?,
+                    certificate_id: row.get(9)?,
+                    beneficiary: row.get(10)?,
+                    reason: row.get(11)?,
+                    reporting_period_start: row.get(12)?,
+                    reporting_period_end: row.get(13)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(retirements)
+    }
+
+    /// Gets the sales of all tokens.
+    pub fn sales(&self) -> IndexerResult<Vec<Sale>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT market_index, market_subindex, token_contract_index, token_contract_subindex,
+            token_id, seller, buyer, amount, paid, block_height, block_slot_time,
+            transaction_hash
+            FROM sales ORDER BY id",
+        )?;
+        let sales = stmt
+            .query_map([], |row| {
+                Ok(Sale {
+                    market_index: row.get(0)?,
+                    market_subindex: row.get(1)?,
+                    token_contract_index: row.get(2)?,
+                    token_contract_subindex: row.get(3)?,
+                    token_id: row.get(4)?,
+                    seller: row.get(5)?,
+                    buyer: row.get(6)?,
+                    amount: row.get(7)?,
+                    paid: row.get(8)?,
+                    block_height: row.get(9)?,
+                    block_slot_time: row.get(10)?,
+                    transaction_hash: row.get(11)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(sales)
+    }
+
+    /// Gets the balance of an owner, or zero if the owner never held the token.
+    pub fn balance_of(
+        &self,
+        contract_index: u64,
+        contract_subindex: u64,
+        token_id: &str,
+        owner: &str,
+    ) -> IndexerResult<u64> {
+        let mut stmt = self.conn.prepare(
+            "SELECT amount FROM balances
+            WHERE contract_index = ?1 AND contract_subindex = ?2 AND token_id = ?3
+            AND owner = ?4",
+        )?;
+        let balance = stmt
+            .query_row(
+                params![contract_index, contract_subindex, token_id, owner],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(balance.unwrap_or(0))
+    }
+}
@@ -0,0 +1,181 @@
+//! Relational store of the indexed events.
+
+use std::path::Path;
+
+use rusqlite::Connection;
+
+use crate::error::IndexerResult;
+
+/// Tables of the store.
+/// Tokens of the Project Token and Carbon Credits contracts are kept in
+/// `tokens`, with the fields specific to each contract in `projects` and
+/// `credits`.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS blocks (
+    block_hash TEXT PRIMARY KEY,
+    block_height INTEGER NOT NULL,
+    block_slot_time TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS contracts (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (contract_index, contract_subindex)
+);
+
+CREATE TABLE IF NOT EXISTS events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    block_height INTEGER NOT NULL,
+    transaction_hash TEXT NOT NULL,
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    contract_name TEXT NOT NULL,
+    tag INTEGER NOT NULL,
+    event TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS tokens (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    supply INTEGER NOT NULL DEFAULT 0,
+    metadata_url TEXT,
+    metadata_hash TEXT,
+    block_height INTEGER NOT NULL,
+    PRIMARY KEY (contract_index, contract_subindex, token_id)
+);
+
+CREATE TABLE IF NOT EXISTS balances (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (contract_index, contract_subindex, token_id, owner)
+);
+
+CREATE TABLE IF NOT EXISTS projects (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    maturity_time INTEGER,
+    metadata_frozen INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (contract_index, contract_subindex, token_id)
+);
+
+CREATE TABLE IF NOT EXISTS maturity_tranches (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    time INTEGER NOT NULL,
+    fraction INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS maturity_amendments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    verifier TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    block_height INTEGER NOT NULL,
+    transaction_hash TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS verifiers (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    verifier TEXT NOT NULL,
+    active INTEGER NOT NULL,
+    PRIMARY KEY (contract_index, contract_subindex, verifier)
+);
+
+CREATE TABLE IF NOT EXISTS quorums (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    threshold INTEGER NOT NULL,
+    PRIMARY KEY (contract_index, contract_subindex)
+);
+
+CREATE TABLE IF NOT EXISTS verifier_weights (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    verifier TEXT NOT NULL,
+    weight INTEGER NOT NULL,
+    PRIMARY KEY (contract_index, contract_subindex, verifier)
+);
+
+CREATE TABLE IF NOT EXISTS verifications (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    verifier TEXT NOT NULL,
+    block_height INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS credits (
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    project_contract_index INTEGER,
+    project_contract_subindex INTEGER,
+    project_token_id TEXT,
+    PRIMARY KEY (contract_index, contract_subindex, token_id)
+);
+
+CREATE TABLE IF NOT EXISTS retirements (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    contract_index INTEGER NOT NULL,
+    contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    block_height INTEGER NOT NULL,
+    block_slot_time TEXT NOT NULL,
+    transaction_hash TEXT NOT NULL,
+    certificate_id INTEGER,
+    beneficiary TEXT,
+    reason TEXT,
+    reporting_period_start INTEGER,
+    reporting_period_end INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS sales (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    market_index INTEGER NOT NULL,
+    market_subindex INTEGER NOT NULL,
+    token_contract_index INTEGER NOT NULL,
+    token_contract_subindex INTEGER NOT NULL,
+    token_id TEXT NOT NULL,
+    seller TEXT NOT NULL,
+    buyer TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    paid INTEGER NOT NULL,
+    block_height INTEGER NOT NULL,
+    block_slot_time TEXT NOT NULL,
+    transaction_hash TEXT NOT NULL
+);
+";
+
+/// Store of the indexer backed by SQLite.
+pub struct Store {
+    pub(crate) conn: Connection,
+}
+
+impl Store {
+    /// Opens the store at the given path, creating the tables if needed.
+    pub fn open(path: &Path) -> IndexerResult<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// Opens a store which is kept in memory.
+    pub fn open_in_memory() -> IndexerResult<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> IndexerResult<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Store { conn })
+    }
+}
@@ -1,7 +1,10 @@
 use concordium_std::{ops::Add, *};
 
 use super::{contract_types::*, error::*, events::*};
-use crate::{carbon_credit_market::state::*, client_utils::types::*};
+use crate::{
+    carbon_credit_market::state::*,
+    client_utils::{client::Client, types::*},
+};
 
 /// Parameters for the `add` method for Market Contract.
 #[derive(Serial, Deserial, SchemaType)]
@@ -16,9 +19,36 @@ pub(crate) struct AddParams {
     /// Royalty basis points. This is equal to Royalty% * 100. So can be a max
     /// of 100*100 `MAX_BASIS_POINTS`
     pub royalty: u16,
+
+    /// Quantity of the token to be sold at this price. Replaces the quantity
+    /// of an earlier listing by the seller at the same price.
+    pub quantity: ContractTokenAmount,
+
+    /// Whether the token has been transferred to the Marketplace or the
+    /// Marketplace is an operator of the seller.
+    pub mode: ListingMode,
 }
 
 /// Adds a new already owned token to the marketplace.
+///
+/// In `Custody` mode the token should have been transferred to the Marketplace.
+/// In `Operator` mode the seller keeps the token and the Marketplace should be
+/// an operator of the seller on the CIS2 contract.
+///
+/// A seller can list parts of the same token at different prices. The total
+/// quantity of all listings cannot be more than the quantity available.
+/// Listing in one mode removes the listings of the seller in the other mode.
+///
+/// It rejects if:
+/// - The marketplace is paused.
+/// - Sender is a contract.
+/// - `commission + royalty` is more than `MAX_BASIS_POINTS`.
+/// - The quantity is zero or the total listed quantity would be more than the
+///   quantity available.
+/// - `Custody`: The token is not in custody of the Marketplace.
+/// - `Operator`: The CIS2 contract is not a verifier contract, the token is not
+///   verified, the Marketplace is not an operator of the seller or the seller
+///   does not hold the token.
 #[receive(
     contract = "carbon_credit_market",
     name = "add",
@@ -32,6 +62,7 @@ pub fn add<S: HasStateApi>(
     host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
+    ensure!(!host.state().paused, MarketplaceError::Paused);
     let sender_account_address: AccountAddress = match ctx.sender() {
         Address::Account(account_address) => account_address,
         Address::Contract(_) => bail!(MarketplaceError::CalledByAContract),
@@ -55,12 +86,78 @@ pub fn add<S: HasStateApi>(
         MarketplaceError::InvalidRoyalty
     );
 
-    let owned_quantity = host
+    let token_owner_info = TokenOwnerInfo::from(&token_info, &sender_account_address);
+    let available_quantity = match params.mode {
+        ListingMode::Custody => {
+            let owned_quantity = host
+                .state()
+                .get_quantity_owned(&token_info, &sender_account_address)?;
+            // Ensure that the quantity owned is greater than 0
+            ensure!(
+                owned_quantity.cmp(&ContractTokenAmount::from(0)).is_gt(),
+                MarketplaceError::InvalidTokenQuantity
+            );
+            owned_quantity
+        }
+        ListingMode::Operator => {
+            // Same checks as for tokens received in custody.
+            ensure!(
+                host.state()
+                    .is_verifier_contract(&params.cis_contract_address),
+                MarketplaceError::InvalidVerifierContract
+            );
+            let is_verified =
+                Client::is_verified(host, params.token_id, params.cis_contract_address)?;
+            ensure!(is_verified, MarketplaceError::TokenNotVerified);
+
+            // Ensure that the Marketplace can transfer the token on behalf of the seller.
+            let is_operator = Client::cis2_is_operator_of(
+                host,
+                Address::Account(sender_account_address),
+                ctx.self_address(),
+                &params.cis_contract_address,
+            )?;
+            ensure!(is_operator, MarketplaceError::NotOperator);
+
+            let balance: ContractTokenAmount = Client::cis2_get_balance(
+                host,
+                params.token_id,
+                &params.cis_contract_address,
+                Address::Account(sender_account_address),
+            )?;
+            // Ensure that the seller holds the token.
+            ensure!(
+                balance.cmp(&ContractTokenAmount::from(0)).is_gt(),
+                MarketplaceError::InvalidTokenQuantity
+            );
+            balance
+        }
+    };
+
+    let state = host.state_mut();
+    // A listing in one mode replaces the listings of the owner in the other mode.
+    if state.get_listing_mode(&token_owner_info).ne(&params.mode) {
+        state.remove_listings(&token_owner_info);
+    }
+    match params.mode {
+        ListingMode::Custody => state.remove_operated_token(&token_owner_info),
+        ListingMode::Operator => state.add_operated_token(&token_owner_info, available_quantity),
+    }
+
+    // Quantity listed at other prices. A listing at the same price is replaced.
+    let listed_quantity = host
         .state()
-        .get_quantity_owned(&token_info, &sender_account_address)?;
-    // Ensure that the quantity owned is greater than 0
+        .get_listings(&token_owner_info)
+        .iter()
+        .filter(|listing| listing.price.ne(&params.price))
+        .fold(ContractTokenAmount::from(0), |total, listing| {
+            total + listing.quantity
+        });
     ensure!(
-        owned_quantity.cmp(&ContractTokenAmount::from(0)).is_ge(),
+        params.quantity.cmp(&ContractTokenAmount::from(0)).is_gt()
+            && (listed_quantity + params.quantity)
+                .cmp(&available_quantity)
+                .is_le(),
         MarketplaceError::InvalidTokenQuantity
     );
 
@@ -70,6 +167,7 @@ pub fn add<S: HasStateApi>(
         &token_info,
         &sender_account_address,
         params.price,
+        params.quantity,
         params.royalty,
     );
 
@@ -77,7 +175,7 @@ pub fn add<S: HasStateApi>(
         token_id: params.token_id,
         token_contract: params.cis_contract_address,
         price: params.price,
-        amount: owned_quantity,
+        amount: params.quantity,
     }))?;
 
     Ok(())
@@ -86,6 +184,10 @@ pub fn add<S: HasStateApi>(
 #[concordium_cfg_test]
 mod test {
     use super::*;
+    use crate::client_utils::client::{
+        BALANCE_OF_ENTRYPOINT_NAME, IS_VERIFIED_ENTRYPOINT_NAME, OPERATOR_OF_ENTRYPOINT_NAME,
+    };
+    use concordium_cis2::{BalanceOfQueryResponse, OperatorOfQueryResponse};
     use concordium_std::test_infrastructure::*;
 
     const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
@@ -114,12 +216,14 @@ mod test {
             price,
             token_id: token_id_1,
             royalty: 0,
+            quantity: token_quantity_1,
+            mode: ListingMode::Custody,
         };
         let parameter_bytes = to_bytes(&add_params);
         ctx.set_parameter(&parameter_bytes);
 
         let mut state_builder = TestStateBuilder::new();
-        let mut state = State::new(&mut state_builder, 250, vec![]);
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
         state.add_owned_token(
             &TokenOwnerInfo {
                 id: token_id_1,
@@ -165,7 +269,10 @@ mod test {
                 .get(&ACCOUNT_0)
                 .unwrap()
                 .to_owned(),
-            price
+            vec![TokenPriceState {
+                quantity: token_quantity_1,
+                price
+            }]
         );
     }
 
@@ -183,12 +290,14 @@ mod test {
             price,
             token_id: token_id_1,
             royalty: 0,
+            quantity: ContractTokenAmount::from(1),
+            mode: ListingMode::Custody,
         };
         let parameter_bytes = to_bytes(&add_params);
         ctx.set_parameter(&parameter_bytes);
 
         let mut state_builder = TestStateBuilder::new();
-        let state = State::new(&mut state_builder, 250, vec![]);
+        let state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
         let mut host = TestHost::new(state, state_builder);
 
         let mut logger = TestLogger::init();
@@ -197,4 +306,347 @@ mod test {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), MarketplaceError::TokenNotInCustody);
     }
+
+    #[concordium_test]
+    fn should_not_add_token_without_quantity_in_custody() {
+        let token_owner_info = TokenOwnerInfo {
+            id: ContractTokenId::from(1),
+            address: CIS_CONTRACT_ADDRESS,
+            owner: ACCOUNT_0,
+        };
+
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let add_params = AddParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            price: Amount::from_ccd(1),
+            token_id: token_owner_info.id,
+            royalty: 0,
+            quantity: ContractTokenAmount::from(1),
+            mode: ListingMode::Custody,
+        };
+        let parameter_bytes = to_bytes(&add_params);
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        state.add_owned_token(&token_owner_info, ContractTokenAmount::from(0));
+        let mut host = TestHost::new(state, state_builder);
+
+        let mut logger = TestLogger::init();
+        let result = add(&ctx, &mut host, &mut logger);
+
+        assert_eq!(result, Err(MarketplaceError::InvalidTokenQuantity));
+        assert!(host.state().get_listed_tokens().is_empty());
+    }
+
+    #[concordium_test]
+    fn should_not_add_when_paused() {
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let add_params = AddParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            price: Amount::from_ccd(1),
+            token_id: ContractTokenId::from(1),
+            royalty: 0,
+            quantity: ContractTokenAmount::from(1),
+            mode: ListingMode::Custody,
+        };
+        let parameter_bytes = to_bytes(&add_params);
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let mut logger = TestLogger::init();
+        let result = add(&ctx, &mut host, &mut logger);
+
+        assert_eq!(result, Err(MarketplaceError::Paused));
+    }
+
+    #[concordium_test]
+    fn should_add_operated_token() {
+        let token_id_1 = ContractTokenId::from(1);
+        let price = Amount::from_ccd(1);
+
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let add_params = AddParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            price,
+            token_id: token_id_1,
+            royalty: 0,
+            quantity: ContractTokenAmount::from(10),
+            mode: ListingMode::Operator,
+        };
+        let parameter_bytes = to_bytes(&add_params);
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut state_builder = TestStateBuilder::new();
+        let state = State::new(
+            &mut state_builder,
+            ACCOUNT_0,
+            250,
+            vec![CIS_CONTRACT_ADDRESS],
+        );
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(IS_VERIFIED_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(vec![true]),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(OPERATOR_OF_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|_, _, _, _| Ok((false, OperatorOfQueryResponse(vec![true])))),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(BALANCE_OF_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|_, _, _, _| {
+                Ok((
+                    false,
+                    BalanceOfQueryResponse(vec![ContractTokenAmount::from(10)]),
+                ))
+            }),
+        );
+
+        let mut logger = TestLogger::init();
+        let result = add(&ctx, &mut host, &mut logger);
+
+        assert!(result.is_ok());
+        let token_owner_info = TokenOwnerInfo {
+            id: token_id_1,
+            address: CIS_CONTRACT_ADDRESS,
+            owner: ACCOUNT_0,
+        };
+        assert_eq!(
+            host.state().get_listing_mode(&token_owner_info),
+            ListingMode::Operator
+        );
+        assert_eq!(
+            host.state()
+                .tokens_operated
+                .get(&token_owner_info)
+                .map(|q| *q),
+            Some(ContractTokenAmount::from(10))
+        );
+        assert_eq!(
+            host.state().get_listed_tokens()[0].quantity,
+            ContractTokenAmount::from(10)
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_add_operated_token_without_operator() {
+        let token_id_1 = ContractTokenId::from(1);
+
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let add_params = AddParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            price: Amount::from_ccd(1),
+            token_id: token_id_1,
+            royalty: 0,
+            quantity: ContractTokenAmount::from(10),
+            mode: ListingMode::Operator,
+        };
+        let parameter_bytes = to_bytes(&add_params);
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut state_builder = TestStateBuilder::new();
+        let state = State::new(
+            &mut state_builder,
+            ACCOUNT_0,
+            250,
+            vec![CIS_CONTRACT_ADDRESS],
+        );
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(IS_VERIFIED_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(vec![true]),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(OPERATOR_OF_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|_, _, _, _| Ok((false, OperatorOfQueryResponse(vec![false])))),
+        );
+
+        let mut logger = TestLogger::init();
+        let result = add(&ctx, &mut host, &mut logger);
+
+        assert_eq!(result.unwrap_err(), MarketplaceError::NotOperator);
+    }
+
+    #[concordium_test]
+    fn should_add_partial_listings() {
+        let token_info = TokenInfo {
+            address: CIS_CONTRACT_ADDRESS,
+            id: ContractTokenId::from(1),
+        };
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        state.add_owned_token(
+            &TokenOwnerInfo::from(&token_info, &ACCOUNT_0),
+            ContractTokenAmount::from(10),
+        );
+        let mut host = TestHost::new(state, state_builder);
+
+        let mut add_listing = |price: u64, quantity: u64| {
+            let mut ctx = TestReceiveContext::default();
+            ctx.set_sender(ADDRESS_0);
+            ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+            let parameter_bytes = to_bytes(&AddParams {
+                cis_contract_address: CIS_CONTRACT_ADDRESS,
+                price: Amount::from_ccd(price),
+                token_id: token_info.id,
+                royalty: 0,
+                quantity: ContractTokenAmount::from(quantity),
+                mode: ListingMode::Custody,
+            });
+            ctx.set_parameter(&parameter_bytes);
+            add(&ctx, &mut host, &mut TestLogger::init())
+        };
+
+        assert!(add_listing(1, 5).is_ok());
+        assert!(add_listing(2, 3).is_ok());
+        // Only 2 tokens are not listed yet.
+        assert_eq!(
+            add_listing(3, 3),
+            Err(MarketplaceError::InvalidTokenQuantity)
+        );
+        // Listing again at the same price replaces the quantity.
+        assert!(add_listing(2, 5).is_ok());
+        assert_eq!(
+            add_listing(2, 6),
+            Err(MarketplaceError::InvalidTokenQuantity)
+        );
+
+        let listed_tokens = host.state().get_listed_tokens();
+        assert_eq!(listed_tokens.len(), 2);
+        assert_eq!(listed_tokens[0].price, Amount::from_ccd(1));
+        assert_eq!(listed_tokens[0].quantity, ContractTokenAmount::from(5));
+        assert_eq!(listed_tokens[1].price, Amount::from_ccd(2));
+        assert_eq!(listed_tokens[1].quantity, ContractTokenAmount::from(5));
+    }
+
+    /// Mocks the calls of an `Operator` listing, with the seller holding the
+    /// balance.
+    fn setup_operator_mocks(host: &mut TestHost<State<TestStateApi>>, balance: u64) {
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(IS_VERIFIED_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(vec![true]),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(OPERATOR_OF_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|_, _, _, _| Ok((false, OperatorOfQueryResponse(vec![true])))),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(BALANCE_OF_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(move |_, _, _, _| {
+                Ok((
+                    false,
+                    BalanceOfQueryResponse(vec![ContractTokenAmount::from(balance)]),
+                ))
+            }),
+        );
+    }
+
+    #[concordium_test]
+    fn should_switch_listing_modes() {
+        let token_owner_info = TokenOwnerInfo {
+            id: ContractTokenId::from(1),
+            address: CIS_CONTRACT_ADDRESS,
+            owner: ACCOUNT_0,
+        };
+        let add_params = |price, mode| AddParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            price,
+            token_id: token_owner_info.id,
+            royalty: 0,
+            quantity: ContractTokenAmount::from(2),
+            mode,
+        };
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(
+            &mut state_builder,
+            ACCOUNT_0,
+            250,
+            vec![CIS_CONTRACT_ADDRESS],
+        );
+        state.add_owned_token(&token_owner_info, ContractTokenAmount::from(2));
+        let mut host = TestHost::new(state, state_builder);
+        setup_operator_mocks(&mut host, 10);
+        let mut logger = TestLogger::init();
+
+        // List the tokens in custody.
+        let parameter_bytes = to_bytes(&add_params(Amount::from_ccd(1), ListingMode::Custody));
+        ctx.set_parameter(&parameter_bytes);
+        let result = add(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+
+        // Listing the tokens held by the seller replaces the custody listing.
+        let parameter_bytes = to_bytes(&add_params(Amount::from_ccd(2), ListingMode::Operator));
+        ctx.set_parameter(&parameter_bytes);
+        let result = add(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            host.state().get_listing_mode(&token_owner_info),
+            ListingMode::Operator
+        );
+        assert_eq!(
+            host.state().get_listings(&token_owner_info),
+            vec![TokenPriceState {
+                quantity: ContractTokenAmount::from(2),
+                price: Amount::from_ccd(2),
+            }]
+        );
+        let listed_tokens = host.state().get_listed_tokens();
+        assert_eq!(listed_tokens.len(), 1);
+        assert_eq!(listed_tokens[0].price, Amount::from_ccd(2));
+
+        // Listing the tokens in custody again replaces the operator listing.
+        let parameter_bytes = to_bytes(&add_params(Amount::from_ccd(3), ListingMode::Custody));
+        ctx.set_parameter(&parameter_bytes);
+        let result = add(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            host.state().get_listing_mode(&token_owner_info),
+            ListingMode::Custody
+        );
+        assert!(host
+            .state()
+            .tokens_operated
+            .get(&token_owner_info)
+            .is_none());
+        assert_eq!(
+            host.state().get_listings(&token_owner_info),
+            vec![TokenPriceState {
+                quantity: ContractTokenAmount::from(2),
+                price: Amount::from_ccd(3),
+            }]
+        );
+        let listed_tokens = host.state().get_listed_tokens();
+        assert_eq!(listed_tokens.len(), 1);
+        assert_eq!(listed_tokens[0].price, Amount::from_ccd(3));
+    }
 }
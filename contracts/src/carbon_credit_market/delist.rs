@@ -0,0 +1,122 @@
+use concordium_std::*;
+
+use super::{contract_types::*, error::*, events::*, state::*};
+use crate::client_utils::types::*;
+
+/// Parameters for the `delist` method for Market Contract.
+#[derive(Serial, Deserial, SchemaType)]
+pub(crate) struct DelistParams {
+    pub cis_contract_address: ContractAddress,
+    pub token_id: ContractTokenId,
+}
+
+/// Removes the price of the sender for a token from the marketplace.
+/// Tokens in custody stay with the Marketplace and can be listed again using
+/// `add` or returned using `withdraw`.
+///
+/// It rejects if:
+/// - Sender is a contract.
+/// - It fails to parse the parameter.
+/// - The token is not listed by the sender.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "delist",
+    parameter = "DelistParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn delist<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let sender_account_address: AccountAddress = match ctx.sender() {
+        Address::Account(account_address) => account_address,
+        Address::Contract(_) => bail!(MarketplaceError::CalledByAContract),
+    };
+
+    let params: DelistParams = ctx.parameter_cursor().get()?;
+    host.state_mut().delist_token(&TokenOwnerInfo {
+        id: params.token_id,
+        address: params.cis_contract_address,
+        owner: sender_account_address,
+    })?;
+
+    logger.log(&ContractEvent::TokenDelisted(TokenDelistedEvent {
+        token_id: params.token_id,
+        token_contract: params.cis_contract_address,
+        owner: Address::Account(sender_account_address),
+    }))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use concordium_std::test_infrastructure::*;
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const ADDRESS_0: Address = Address::Account(ACCOUNT_0);
+    const CIS_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+    const MARKET_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 2,
+        subindex: 0,
+    };
+
+    #[concordium_test]
+    fn should_delist_token() {
+        let token_id_1 = ContractTokenId::from(1);
+        let token_info = TokenInfo {
+            address: CIS_CONTRACT_ADDRESS,
+            id: token_id_1,
+        };
+        let token_owner_info = TokenOwnerInfo::from(&token_info, &ACCOUNT_0);
+
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let parameter_bytes = to_bytes(&DelistParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            token_id: token_id_1,
+        });
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        state.add_owned_token(&token_owner_info, ContractTokenAmount::from(10));
+        state.list_token(
+            &mut state_builder,
+            &token_info,
+            &ACCOUNT_0,
+            Amount::from_ccd(1),
+            ContractTokenAmount::from(10),
+            0,
+        );
+
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+        let result = delist(&ctx, &mut host, &mut logger);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            host.state()
+                .get_listed_token(&token_info, &ACCOUNT_0, Amount::from_ccd(1)),
+            Err(MarketplaceError::TokenNotListed)
+        );
+        assert_eq!(
+            host.state().get_quantity_owned(&token_info, &ACCOUNT_0),
+            Ok(ContractTokenAmount::from(10))
+        );
+
+        // Delisting again fails.
+        let result = delist(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::TokenNotListed));
+    }
+}
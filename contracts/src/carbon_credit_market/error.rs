@@ -3,7 +3,7 @@
 
 use concordium_std::*;
 
-use crate::client_utils::errors::Cis2ClientError;
+use crate::client_utils::{errors::Cis2ClientError, upgrade::ContractUpgradeError};
 
 #[derive(Serialize, Debug, PartialEq, Eq, Reject, SchemaType)]
 pub enum MarketplaceError {
@@ -20,7 +20,11 @@ pub enum MarketplaceError {
     CalledByAnAccount,
     LogError,
     InvalidVerifierContract,
-    TokenNotVerified
+    TokenNotVerified,
+    NotOperator,
+    Unauthorized,
+    Paused,
+    ContractUpgradeError(ContractUpgradeError),
 }
 
 impl From<Cis2ClientError> for MarketplaceError {
@@ -29,6 +33,12 @@ impl From<Cis2ClientError> for MarketplaceError {
     }
 }
 
+impl From<ContractUpgradeError> for MarketplaceError {
+    fn from(e: ContractUpgradeError) -> Self {
+        MarketplaceError::ContractUpgradeError(e)
+    }
+}
+
 impl From<ParseError> for MarketplaceError {
     fn from(_: ParseError) -> Self {
         MarketplaceError::ParseParams
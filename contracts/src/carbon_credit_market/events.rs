@@ -1,8 +1,14 @@
-use concordium_std::{Address, ContractAddress, Amount, SchemaType, Serial};
+use concordium_std::{
+    AccountAddress, Address, Amount, ContractAddress, Deserial, SchemaType, Serial,
+};
 
 use crate::carbon_credits::contract_types::{ContractTokenAmount, ContractTokenId};
+use crate::client_utils::{
+    roles::{AdminProposedEvent, AdminTransferredEvent, RoleUpdatedEvent},
+    types::{PauseEvent, VerifierContractUpdatedEvent},
+};
 
-#[derive(Serial, SchemaType)]
+#[derive(Serial, Deserial, SchemaType)]
 pub struct TokenReceivedEvent {
     pub token_id: ContractTokenId,
     pub token_contract: ContractAddress,
@@ -10,7 +16,7 @@ pub struct TokenReceivedEvent {
     pub amount: ContractTokenAmount,
 }
 
-#[derive(Serial, SchemaType)]
+#[derive(Serial, Deserial, SchemaType)]
 pub struct TokenListedEvent {
     pub token_id: ContractTokenId,
     pub token_contract: ContractAddress,
@@ -18,7 +24,7 @@ pub struct TokenListedEvent {
     pub price: Amount
 }
 
-#[derive(Serial, SchemaType)]
+#[derive(Serial, Deserial, SchemaType)]
 pub struct TokenTransferredEvent {
     pub token_id: ContractTokenId,
     pub token_contract: ContractAddress,
@@ -27,9 +33,63 @@ pub struct TokenTransferredEvent {
     pub amount: ContractTokenAmount,
 }
 
-#[derive(Serial, SchemaType)]
+/// Tokens bought from a listing. Logged together with `TokenTransferred`.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct TokenSoldEvent {
+    pub token_id: ContractTokenId,
+    pub token_contract: ContractAddress,
+    pub seller: Address,
+    pub buyer: Address,
+    pub amount: ContractTokenAmount,
+    /// Amount paid by the buyer, including the commission.
+    pub paid: Amount,
+    /// Commission paid to the Marketplace.
+    pub commission: Amount,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
+pub struct TokenDelistedEvent {
+    pub token_id: ContractTokenId,
+    pub token_contract: ContractAddress,
+    pub owner: Address,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
+pub struct TokenWithdrawnEvent {
+    pub token_id: ContractTokenId,
+    pub token_contract: ContractAddress,
+    pub owner: Address,
+    pub amount: ContractTokenAmount,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
+pub struct CommissionUpdatedEvent {
+    /// Commission basis points. equals to percent * 100
+    pub commission: u16,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
+pub struct CommissionRecipientUpdatedEvent {
+    /// Account to which the commission is paid.
+    pub recipient: AccountAddress,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
 pub enum ContractEvent {
     TokenReceived(TokenReceivedEvent),
     TokenListed(TokenListedEvent),
     TokenTransferred(TokenTransferredEvent),
+    TokenDelisted(TokenDelistedEvent),
+    TokenWithdrawn(TokenWithdrawnEvent),
+    RoleGranted(RoleUpdatedEvent),
+    RoleRevoked(RoleUpdatedEvent),
+    CommissionUpdated(CommissionUpdatedEvent),
+    AdminProposed(AdminProposedEvent),
+    AdminTransferred(AdminTransferredEvent),
+    Paused(PauseEvent),
+    Unpaused(PauseEvent),
+    VerifierContractAdded(VerifierContractUpdatedEvent),
+    VerifierContractRemoved(VerifierContractUpdatedEvent),
+    CommissionRecipientUpdated(CommissionRecipientUpdatedEvent),
+    TokenSold(TokenSoldEvent),
 }
@@ -37,8 +37,10 @@ fn init<S: HasStateApi>(
         MarketplaceError::InvalidCommission
     );
 
+    // The account creating the contract is its admin.
     Ok(State::new(
         state_builder,
+        ctx.init_origin(),
         params.commission,
         params.verifier_contracts,
     ))
@@ -56,6 +58,7 @@ mod test {
 
         let state = State::new(
             &mut state_builder,
+            AccountAddress([0u8; 32]),
             250,
             vec![ContractAddress {
                 index: 1,
@@ -74,6 +77,7 @@ mod test {
 
         let parameter_bytes = to_bytes(&init_params);
         ctx.set_parameter(&parameter_bytes);
+        ctx.set_init_origin(AccountAddress([0u8; 32]));
 
         let result = init(&ctx, &mut TestStateBuilder::new());
 
@@ -82,5 +86,10 @@ mod test {
             host.state().commission.percentage_basis,
             init_params.commission
         );
+        // The account which created the contract is its admin.
+        assert_eq!(
+            result.ok().unwrap().roles.admin(),
+            Address::Account(AccountAddress([0u8; 32]))
+        );
     }
 }
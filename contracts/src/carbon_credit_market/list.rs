@@ -50,7 +50,7 @@ mod test {
         ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
 
         let mut state_builder = TestStateBuilder::new();
-        let mut state = State::new(&mut state_builder, 250, vec![]);
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
         state.add_owned_token(
             &TokenOwnerInfo {
                 id: ContractTokenId::from(1),
@@ -79,13 +79,19 @@ mod test {
                 },
                 token_prices: {
                     let mut map = state_builder.new_map();
-                    map.insert(ACCOUNT_0, Amount::from_ccd(1));
+                    map.insert(
+                        ACCOUNT_0,
+                        vec![TokenPriceState {
+                            quantity: ContractTokenAmount::from(1),
+                            price: Amount::from_ccd(1),
+                        }],
+                    );
                     map
                 },
             },
         );
 
-        let result = list(&ctx, &mut TestHost::new(state, state_builder));
+        let result = list(&ctx, &TestHost::new(state, state_builder));
         assert!(result.is_ok());
         let tokens_list = result.unwrap();
         assert_eq!(tokens_list.0.len(), 1);
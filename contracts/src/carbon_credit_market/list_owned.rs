@@ -1,7 +1,9 @@
-use concordium_std::*;
+use concordium_std::{ops::Sub, *};
 
 use super::{contract_types::*, state::*};
+use crate::client_utils::types::ContractTokenAmount;
 
+/// Returns the tokens in custody of the sender which are not listed.
 #[receive(
     contract = "carbon_credit_market",
     name = "list_owned",
@@ -19,21 +21,25 @@ fn list_owned<S: HasStateApi>(
         .tokens_owned
         .iter()
         .filter(|f| sender.matches_account(&f.0.owner))
-        .filter(|f| {
-            match host.state().tokens_listed.get(&TokenInfo {
-                address: f.0.address,
-                id: f.0.id,
-            }) {
-                Some(token) => token.token_prices.get(&f.0.owner).is_none(),
-                None => true,
+        .filter_map(|f| {
+            // Tokens in custody are not listed if the owner sells the token
+            // using the Marketplace as an operator.
+            let listed_quantity = match host.state().get_listing_mode(&f.0) {
+                ListingMode::Operator => ContractTokenAmount::from(0),
+                ListingMode::Custody => host.state().get_listed_quantity(&f.0),
+            };
+            // Only the quantity in custody which is not listed is returned.
+            let quantity = f.1.sub(listed_quantity);
+            match quantity.cmp(&ContractTokenAmount::from(0)).is_gt() {
+                true => Some(TokenOwnedListItem {
+                    contract: f.0.address,
+                    token_id: f.0.id,
+                    owner: f.0.owner,
+                    quantity,
+                }),
+                false => None,
             }
         })
-        .map(|f| TokenOwnedListItem {
-            contract: f.0.address,
-            token_id: f.0.id,
-            owner: f.0.owner,
-            quantity: *f.1,
-        })
         .collect();
 
     Ok(list)
@@ -8,4 +8,12 @@ pub mod utils;
 pub mod list;
 pub mod list_owned;
 pub mod on_cis2_recieved;
-pub mod events;
\ No newline at end of file
+pub mod events;
+pub mod delist;
+pub mod withdraw;
+pub mod role_operations;
+pub mod set_commission;
+pub mod pause;
+pub mod upgrade;
+pub mod verifier_contract_operations;
+pub mod verifier_contracts;
\ No newline at end of file
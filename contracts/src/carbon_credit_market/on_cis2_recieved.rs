@@ -73,6 +73,7 @@ fn on_cis2_received<S: HasStateApi>(
 #[concordium_cfg_test]
 mod test {
     use super::*;
+    use crate::client_utils::client::IS_VERIFIED_ENTRYPOINT_NAME;
     use concordium_cis2::AdditionalData;
     use concordium_std::test_infrastructure::*;
 
@@ -106,8 +107,18 @@ mod test {
         ctx.set_parameter(&parameter_bytes);
 
         let mut state_builder = TestStateBuilder::new();
-        let state = State::new(&mut state_builder, 250, vec![CIS_CONTRACT_ADDRESS]);
+        let state = State::new(
+            &mut state_builder,
+            ACCOUNT_0,
+            250,
+            vec![CIS_CONTRACT_ADDRESS],
+        );
         let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(IS_VERIFIED_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(vec![true]),
+        );
         let mut logger = TestLogger::init();
 
         let result = on_cis2_received(&ctx, &mut host, &mut logger);
@@ -0,0 +1,92 @@
+use concordium_std::*;
+
+use crate::client_utils::{
+    roles::Role,
+    types::{PauseEvent, SetPausedParams},
+};
+
+use super::{contract_types::*, error::*, events::*};
+
+/// Pauses or unpauses the sales and listings of the marketplace.
+///
+/// It rejects if:
+/// - The sender does not have the `Pauser` role.
+/// - It fails to parse the parameter.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "setPaused",
+    parameter = "SetPausedParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn set_paused<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let SetPausedParams { paused } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let pauser = ctx.sender();
+    ensure!(
+        state.roles.has_role(&pauser, Role::Pauser),
+        MarketplaceError::Unauthorized
+    );
+
+    state.paused = paused;
+    let event = PauseEvent { pauser };
+    logger.log(&if paused {
+        ContractEvent::Paused(event)
+    } else {
+        ContractEvent::Unpaused(event)
+    })?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::carbon_credit_market::state::State;
+    use concordium_std::test_infrastructure::*;
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const PAUSER: Address = Address::Account(AccountAddress([1u8; 32]));
+
+    #[concordium_test]
+    fn should_pause_and_unpause() {
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        state.roles.grant(&PAUSER, Role::Pauser, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&SetPausedParams { paused: true });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        // Only a pauser can pause, not even the admin.
+        ctx.set_sender(Address::Account(ACCOUNT_0));
+        let result = set_paused(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::Unauthorized));
+
+        ctx.set_sender(PAUSER);
+        let result = set_paused(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert!(host.state().paused);
+
+        let parameter_bytes = to_bytes(&SetPausedParams { paused: false });
+        ctx.set_parameter(&parameter_bytes);
+        let result = set_paused(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert!(!host.state().paused);
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::Paused(PauseEvent { pauser: PAUSER })),
+                to_bytes(&ContractEvent::Unpaused(PauseEvent { pauser: PAUSER })),
+            ]
+        );
+    }
+}
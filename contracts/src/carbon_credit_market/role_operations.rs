@@ -0,0 +1,244 @@
+use concordium_std::*;
+
+use crate::client_utils::roles::{
+    AdminProposedEvent, AdminTransferredEvent, ProposeAdminParams, Role, RoleParams,
+    RoleUpdatedEvent,
+};
+
+use super::{contract_types::*, error::*, events::*};
+
+/// Grants a role to an address.
+///
+/// It rejects if:
+/// - The sender is not an admin.
+/// - It fails to parse the parameter.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "grantRole",
+    parameter = "RoleParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn grant_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let RoleParams { address, role } = ctx.parameter_cursor().get()?;
+    let (state, builder) = host.state_and_builder();
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        MarketplaceError::Unauthorized
+    );
+
+    state.roles.grant(&address, role, builder);
+    logger.log(&ContractEvent::RoleGranted(RoleUpdatedEvent {
+        address,
+        role,
+    }))?;
+
+    Ok(())
+}
+
+/// Revokes a role from an address.
+///
+/// It rejects if:
+/// - The sender is not an admin.
+/// - It fails to parse the parameter.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "revokeRole",
+    parameter = "RoleParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn revoke_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let RoleParams { address, role } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        MarketplaceError::Unauthorized
+    );
+
+    state.roles.revoke(&address, role);
+    logger.log(&ContractEvent::RoleRevoked(RoleUpdatedEvent {
+        address,
+        role,
+    }))?;
+
+    Ok(())
+}
+
+/// Proposes a new admin of the marketplace. The proposed admin becomes the
+/// admin once it calls `acceptAdmin`.
+///
+/// It rejects if:
+/// - The sender is not the admin.
+/// - It fails to parse the parameter.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "proposeAdmin",
+    parameter = "ProposeAdminParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn propose_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let ProposeAdminParams {
+        admin: proposed_admin,
+    } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    let admin = state.roles.admin();
+    ensure!(ctx.sender() == admin, MarketplaceError::Unauthorized);
+    state.roles.propose_admin(proposed_admin);
+    logger.log(&ContractEvent::AdminProposed(AdminProposedEvent {
+        admin,
+        proposed_admin,
+    }))?;
+
+    Ok(())
+}
+
+/// Makes the sender the admin of the marketplace.
+///
+/// It rejects if:
+/// - The sender is not the admin proposed with `proposeAdmin`.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "acceptAdmin",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn accept_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let admin = ctx.sender();
+    let previous_admin = host
+        .state_mut()
+        .roles
+        .accept_admin(&admin)
+        .ok_or(MarketplaceError::Unauthorized)?;
+    logger.log(&ContractEvent::AdminTransferred(AdminTransferredEvent {
+        previous_admin,
+        admin,
+    }))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::carbon_credit_market::state::State;
+    use concordium_std::test_infrastructure::*;
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const ADMIN: Address = Address::Account(ACCOUNT_0);
+    const ACCOUNT_1: Address = Address::Account(AccountAddress([1u8; 32]));
+    const CONTRACT: Address = Address::Contract(ContractAddress {
+        index: 1,
+        subindex: 0,
+    });
+
+    #[concordium_test]
+    fn should_grant_and_revoke_roles() {
+        let mut state_builder = TestStateBuilder::new();
+        let state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        let mut host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&RoleParams {
+            address: ACCOUNT_1,
+            role: Role::FeeManager,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        // Only an admin can grant roles.
+        ctx.set_sender(ACCOUNT_1);
+        let mut logger = TestLogger::init();
+        let result = grant_role(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::Unauthorized));
+
+        ctx.set_sender(ADMIN);
+        let result = grant_role(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert!(host.state().roles.has_role(&ACCOUNT_1, Role::FeeManager));
+        assert_eq!(
+            logger.logs,
+            vec![to_bytes(&ContractEvent::RoleGranted(RoleUpdatedEvent {
+                address: ACCOUNT_1,
+                role: Role::FeeManager,
+            }))]
+        );
+
+        let result = revoke_role(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert!(!host.state().roles.has_role(&ACCOUNT_1, Role::FeeManager));
+    }
+
+    #[concordium_test]
+    fn should_transfer_admin() {
+        let mut state_builder = TestStateBuilder::new();
+        let state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let propose = |host: &mut TestHost<State<TestStateApi>>, sender, admin| {
+            let parameter_bytes = to_bytes(&ProposeAdminParams { admin });
+            let mut ctx = TestReceiveContext::default();
+            ctx.set_sender(sender);
+            ctx.set_parameter(&parameter_bytes);
+            propose_admin(&ctx, host, &mut TestLogger::init())
+        };
+
+        // Only the admin can propose an admin, which can also be a contract.
+        assert_eq!(
+            propose(&mut host, ACCOUNT_1, ACCOUNT_1),
+            Err(MarketplaceError::Unauthorized)
+        );
+        assert!(propose(&mut host, ADMIN, CONTRACT).is_ok());
+        // A new proposal replaces the previous one.
+        assert!(propose(&mut host, ADMIN, ACCOUNT_1).is_ok());
+
+        // Only the proposed admin can accept.
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADMIN);
+        let result = accept_admin(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::Unauthorized));
+        ctx.set_sender(CONTRACT);
+        let result = accept_admin(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::Unauthorized));
+
+        ctx.set_sender(ACCOUNT_1);
+        let result = accept_admin(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert_eq!(host.state().roles.admin(), ACCOUNT_1);
+        assert!(!host.state().roles.has_role(&ADMIN, Role::Admin));
+        assert_eq!(
+            logger.logs,
+            vec![to_bytes(&ContractEvent::AdminTransferred(
+                AdminTransferredEvent {
+                    previous_admin: ADMIN,
+                    admin: ACCOUNT_1,
+                }
+            ))]
+        );
+    }
+}
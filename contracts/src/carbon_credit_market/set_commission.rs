@@ -0,0 +1,170 @@
+use concordium_std::*;
+
+use crate::client_utils::roles::Role;
+
+use super::{contract_types::*, error::*, events::*, state::*};
+
+/// Parameters for the `setCommission` method for Market Contract.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct SetCommissionParams {
+    /// Commission basis points. equals to percent * 100
+    /// This can me atmost equal to 100*100 = 10000(MAX_BASIS_POINTS)
+    pub commission: u16,
+}
+
+/// Sets the commission charged by the marketplace on every sale.
+///
+/// It rejects if:
+/// - The sender does not have the `FeeManager` role.
+/// - It fails to parse the parameter.
+/// - The commission is more than `MAX_BASIS_POINTS`.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "setCommission",
+    parameter = "SetCommissionParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn set_commission<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let SetCommissionParams { commission } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::FeeManager),
+        MarketplaceError::Unauthorized
+    );
+    ensure!(
+        commission.cmp(&MAX_BASIS_POINTS).is_le(),
+        MarketplaceError::InvalidCommission
+    );
+
+    state.commission = Commission {
+        percentage_basis: commission,
+    };
+    logger.log(&ContractEvent::CommissionUpdated(CommissionUpdatedEvent {
+        commission,
+    }))?;
+
+    Ok(())
+}
+
+/// Parameters for the `setCommissionRecipient` method for Market Contract.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct SetCommissionRecipientParams {
+    /// Account to which the commission is paid.
+    pub recipient: AccountAddress,
+}
+
+/// Sets the account to which the commission of every sale is paid.
+///
+/// It rejects if:
+/// - The sender does not have the `FeeManager` role.
+/// - It fails to parse the parameter.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "setCommissionRecipient",
+    parameter = "SetCommissionRecipientParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn set_commission_recipient<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let SetCommissionRecipientParams { recipient } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::FeeManager),
+        MarketplaceError::Unauthorized
+    );
+
+    state.commission_recipient = recipient;
+    logger.log(&ContractEvent::CommissionRecipientUpdated(
+        CommissionRecipientUpdatedEvent { recipient },
+    ))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use concordium_std::test_infrastructure::*;
+
+    const FEE_MANAGER: Address = Address::Account(AccountAddress([0u8; 32]));
+    const ACCOUNT_1: Address = Address::Account(AccountAddress([1u8; 32]));
+
+    #[concordium_test]
+    fn should_set_commission() {
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, AccountAddress([0u8; 32]), 250, vec![]);
+        state
+            .roles
+            .grant(&FEE_MANAGER, Role::FeeManager, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+
+        let set = |host: &mut TestHost<State<TestStateApi>>, sender, commission| {
+            let parameter_bytes = to_bytes(&SetCommissionParams { commission });
+            let mut ctx = TestReceiveContext::default();
+            ctx.set_sender(sender);
+            ctx.set_parameter(&parameter_bytes);
+            set_commission(&ctx, host, &mut TestLogger::init())
+        };
+
+        assert_eq!(
+            set(&mut host, ACCOUNT_1, 100),
+            Err(MarketplaceError::Unauthorized)
+        );
+        assert_eq!(
+            set(&mut host, FEE_MANAGER, MAX_BASIS_POINTS + 1),
+            Err(MarketplaceError::InvalidCommission)
+        );
+        assert_eq!(host.state().commission.percentage_basis, 250);
+
+        assert!(set(&mut host, FEE_MANAGER, 100).is_ok());
+        assert_eq!(host.state().commission.percentage_basis, 100);
+    }
+
+    #[concordium_test]
+    fn should_set_commission_recipient() {
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, AccountAddress([0u8; 32]), 250, vec![]);
+        state
+            .roles
+            .grant(&FEE_MANAGER, Role::FeeManager, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+        // The commission is paid to the account creating the contract.
+        assert_eq!(host.state().commission_recipient, AccountAddress([0u8; 32]));
+
+        let parameter_bytes = to_bytes(&SetCommissionRecipientParams {
+            recipient: AccountAddress([1u8; 32]),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(ACCOUNT_1);
+        let result = set_commission_recipient(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::Unauthorized));
+
+        ctx.set_sender(FEE_MANAGER);
+        let result = set_commission_recipient(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert_eq!(host.state().commission_recipient, AccountAddress([1u8; 32]));
+        assert_eq!(
+            logger.logs,
+            vec![to_bytes(&ContractEvent::CommissionRecipientUpdated(
+                CommissionRecipientUpdatedEvent {
+                    recipient: AccountAddress([1u8; 32]),
+                }
+            ))]
+        );
+    }
+}
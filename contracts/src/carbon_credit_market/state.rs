@@ -2,11 +2,11 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use std::ops::Sub;
+use std::ops::{Add, Sub};
 
 use concordium_std::*;
 
-use crate::client_utils::types::*;
+use crate::client_utils::{roles::Roles, types::*, upgrade::StateVersion};
 
 use super::error::*;
 
@@ -51,6 +51,17 @@ impl TokenOwnerInfo {
     }
 }
 
+/// How the tokens of a listing are held while they are on sale.
+#[derive(Clone, Copy, Serialize, SchemaType, PartialEq, Eq, Debug)]
+pub enum ListingMode {
+    /// Tokens have been transferred to the Marketplace and are held in custody.
+    Custody,
+    /// Tokens stay with the seller. The Marketplace is an operator of the seller
+    /// and transfers the tokens directly from the seller to the buyer.
+    Operator,
+}
+
+/// A single listing of a token by an owner.
 #[derive(Clone, Serialize, Copy, PartialEq, Eq, Debug)]
 pub struct TokenPriceState {
     pub quantity: ContractTokenAmount,
@@ -98,7 +109,9 @@ pub struct TokenOwnedListItem {
 #[concordium(state_parameter = "S")]
 pub struct TokenListState<S: HasStateApi> {
     pub token_royalty: TokenRoyaltyState,
-    pub token_prices: StateMap<AccountAddress, Amount, S>,
+    /// Listings of each owner. An owner can have several listings at
+    /// different prices, each with its own quantity.
+    pub token_prices: StateMap<AccountAddress, Vec<TokenPriceState>, S>,
 }
 
 impl<S: HasStateApi> TokenListState<S> {
@@ -106,7 +119,7 @@ impl<S: HasStateApi> TokenListState<S> {
         state_builder: &mut StateBuilder<S>,
         owner: &AccountAddress,
         royalty: u16,
-        price: Amount,
+        listing: TokenPriceState,
     ) -> Self {
         TokenListState {
             token_royalty: TokenRoyaltyState {
@@ -115,40 +128,61 @@ impl<S: HasStateApi> TokenListState<S> {
             },
             token_prices: {
                 let mut map = state_builder.new_map();
-                map.insert(owner.to_owned(), price);
+                map.insert(owner.to_owned(), vec![listing]);
                 map
             },
         }
     }
 }
 
+/// Version of the layout of [`State`]. See [`crate::client_utils::upgrade`]
+/// for when it is increased.
+pub const STATE_VERSION: StateVersion = 1;
+
 #[derive(Serial, DeserialWithState, StateClone)]
 #[concordium(state_parameter = "S")]
 pub struct State<S: HasStateApi> {
+    /// Version of the layout of the state. Always the first field.
+    pub version: StateVersion,
     pub commission: Commission,
+    /// Account to which the commission is paid. The account creating the
+    /// contract until a fee manager sets another one, so that the admin can
+    /// also be a contract.
+    pub commission_recipient: AccountAddress,
     pub tokens_owned: StateMap<TokenOwnerInfo, ContractTokenAmount, S>,
     pub tokens_listed: StateMap<TokenInfo, TokenListState<S>, S>,
+    /// Tokens listed by sellers who made the Marketplace their operator.
+    /// The quantity is the seller balance available for sale.
+    pub tokens_operated: StateMap<TokenOwnerInfo, ContractTokenAmount, S>,
     // Contracts from which incoming CIS2 transfers will be accepted
     pub verifier_contracts: StateSet<ContractAddress, S>,
+    /// Roles of the addresses.
+    pub roles: Roles<S>,
+    /// Whether the sales and listings are paused.
+    pub paused: bool,
 }
 
 impl<S> State<S>
 where
     S: HasStateApi,
 {
-    /// Creates a new state with the given commission.
+    /// Creates a new state with the given admin and commission.
     /// The commission is given as a percentage basis, i.e. 10000 is 100%.
     pub fn new(
         state_builder: &mut StateBuilder<S>,
+        admin: AccountAddress,
         commission: u16,
         verifier_contracts: Vec<ContractAddress>,
     ) -> Self {
         State {
+            version: STATE_VERSION,
             commission: Commission {
                 percentage_basis: commission,
             },
+            commission_recipient: admin,
             tokens_owned: state_builder.new_map(),
             tokens_listed: state_builder.new_map(),
+            tokens_operated: state_builder.new_map(),
             verifier_contracts: {
                 let mut set = state_builder.new_set();
                 for contract in verifier_contracts {
@@ -156,6 +190,8 @@ where
                 }
                 set
             },
+            roles: Roles::new(Address::Account(admin), state_builder),
+            paused: false,
         }
     }
 
@@ -164,6 +200,18 @@ where
         self.verifier_contracts.contains(contract)
     }
 
+    /// Adds a verifier contract.
+    /// Returns false if the contract is already a verifier contract.
+    pub fn add_verifier_contract(&mut self, contract: ContractAddress) -> bool {
+        self.verifier_contracts.insert(contract)
+    }
+
+    /// Removes a verifier contract.
+    /// Returns false if the contract is not a verifier contract.
+    pub fn remove_verifier_contract(&mut self, contract: &ContractAddress) -> bool {
+        self.verifier_contracts.remove(contract)
+    }
+
     pub fn add_owned_token(
         &mut self,
         token_owner_info: &TokenOwnerInfo,
@@ -177,24 +225,163 @@ where
             .or_insert(quantity);
     }
 
+    /// Sets the quantity of a token which is sold using the Marketplace as an
+    /// operator of the owner.
+    pub fn add_operated_token(
+        &mut self,
+        token_owner_info: &TokenOwnerInfo,
+        quantity: ContractTokenAmount,
+    ) {
+        self.tokens_operated
+            .insert(token_owner_info.clone(), quantity);
+    }
+
+    /// Removes a token which was sold using the Marketplace as an operator of
+    /// the owner.
+    pub fn remove_operated_token(&mut self, token_owner_info: &TokenOwnerInfo) {
+        self.tokens_operated.remove(token_owner_info);
+    }
+
+    /// Returns the way the listed token of the owner is held.
+    pub fn get_listing_mode(&self, token_owner_info: &TokenOwnerInfo) -> ListingMode {
+        match self.tokens_operated.get(token_owner_info) {
+            Some(_) => ListingMode::Operator,
+            None => ListingMode::Custody,
+        }
+    }
+
     /// Adds a token to Buyable Token List.
+    /// If the owner already has a listing at the same price, its quantity is
+    /// replaced.
     pub fn list_token(
         &mut self,
         state_builder: &mut StateBuilder<S>,
         token_info: &TokenInfo,
         owner: &AccountAddress,
         price: Amount,
+        quantity: ContractTokenAmount,
         royalty: u16,
     ) {
+        let listing = TokenPriceState { quantity, price };
         self.tokens_listed
             .entry(token_info.clone())
             .and_modify(|l| {
-                l.token_prices.insert(owner.to_owned(), price);
+                let mut listings = l.token_prices.entry(*owner).or_insert_with(Vec::new);
+                match listings.iter_mut().find(|p| p.price.eq(&price)) {
+                    Some(existing) => existing.quantity = quantity,
+                    None => listings.push(listing),
+                }
             })
-            .or_insert(TokenListState::new(state_builder, owner, royalty, price));
+            .or_insert(TokenListState::new(state_builder, owner, royalty, listing));
     }
 
-    /// Decreases the quantity of a token in the buyable token list.
+    /// Gets all listings of a token by the owner.
+    pub fn get_listings(&self, token_owner_info: &TokenOwnerInfo) -> Vec<TokenPriceState> {
+        self.tokens_listed
+            .get(&token_owner_info.into())
+            .and_then(|token| {
+                token
+                    .token_prices
+                    .get(&token_owner_info.owner)
+                    .map(|listings| listings.to_owned())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Gets the total quantity of all listings of a token by the owner.
+    pub fn get_listed_quantity(&self, token_owner_info: &TokenOwnerInfo) -> ContractTokenAmount {
+        self.get_listings(token_owner_info)
+            .iter()
+            .fold(ContractTokenAmount::from(0), |total, listing| {
+                total.add(listing.quantity)
+            })
+    }
+
+    /// Decreases the quantity of the listing of the owner at the given price.
+    /// The listing is removed once its quantity reaches zero.
+    pub fn decrease_listing_quantity(
+        &mut self,
+        token_owner_info: &TokenOwnerInfo,
+        price: Amount,
+        delta: ContractTokenAmount,
+    ) -> Result<(), MarketplaceError> {
+        let mut token = self
+            .tokens_listed
+            .get_mut(&token_owner_info.into())
+            .ok_or(MarketplaceError::TokenNotListed)?;
+        let is_empty = {
+            let mut listings = token
+                .token_prices
+                .get_mut(&token_owner_info.owner)
+                .ok_or(MarketplaceError::TokenNotListed)?;
+            let listing = listings
+                .iter_mut()
+                .find(|l| l.price.eq(&price))
+                .ok_or(MarketplaceError::TokenNotListed)?;
+            ensure!(
+                listing.quantity.cmp(&delta).is_ge(),
+                MarketplaceError::InvalidTokenQuantity
+            );
+            listing.quantity -= delta;
+            listings.retain(|l| l.quantity.cmp(&ContractTokenAmount::from(0)).is_gt());
+            listings.is_empty()
+        };
+        if is_empty {
+            token.token_prices.remove(&token_owner_info.owner);
+        }
+
+        Ok(())
+    }
+
+    /// Trims the listings of the owner so that at most `available` tokens are
+    /// listed. Earlier listings are kept over later ones.
+    fn cap_listings(&mut self, token_owner_info: &TokenOwnerInfo, available: ContractTokenAmount) {
+        if let Some(mut token) = self.tokens_listed.get_mut(&token_owner_info.into()) {
+            let is_empty = match token.token_prices.get_mut(&token_owner_info.owner) {
+                Some(mut listings) => {
+                    let mut remaining = available;
+                    for listing in listings.iter_mut() {
+                        if listing.quantity.cmp(&remaining).is_gt() {
+                            listing.quantity = remaining;
+                        }
+                        remaining -= listing.quantity;
+                    }
+                    listings.retain(|l| l.quantity.cmp(&ContractTokenAmount::from(0)).is_gt());
+                    listings.is_empty()
+                }
+                None => false,
+            };
+            if is_empty {
+                token.token_prices.remove(&token_owner_info.owner);
+            }
+        }
+    }
+
+    /// Removes all listings of a token by the owner, if any.
+    pub fn remove_listings(&mut self, token_owner_info: &TokenOwnerInfo) {
+        if let Some(mut token) = self.tokens_listed.get_mut(&token_owner_info.into()) {
+            token.token_prices.remove(&token_owner_info.owner);
+        }
+    }
+
+    /// Removes the price of the owner from the buyable token list.
+    /// Tokens in custody stay with the Marketplace. Listings using the
+    /// Marketplace as an operator are removed.
+    pub fn delist_token(
+        &mut self,
+        token_owner_info: &TokenOwnerInfo,
+    ) -> Result<(), MarketplaceError> {
+        self.tokens_listed
+            .get_mut(&token_owner_info.into())
+            .and_then(|mut token| token.token_prices.remove_and_get(&token_owner_info.owner))
+            .ok_or(MarketplaceError::TokenNotListed)?;
+        self.tokens_operated.remove(token_owner_info);
+
+        Ok(())
+    }
+
+    /// Decreases the quantity of a token in custody.
+    /// Listings of the owner are trimmed to the quantity left in custody.
     pub fn decrease_listed_quantity(
         &mut self,
         token_owner_info: &TokenOwnerInfo,
@@ -204,13 +391,30 @@ where
             let new_quantity = quantity.sub(delta);
             if new_quantity.eq(&ContractTokenAmount::from(0)) {
                 self.tokens_owned.remove(token_owner_info);
-                if let Some(mut token) = self.tokens_listed.get_mut(&token_owner_info.into()) {
-                    token.token_prices.remove(&token_owner_info.owner);
-                };
             } else {
                 self.tokens_owned
                     .insert(token_owner_info.clone(), new_quantity);
             }
+            self.cap_listings(token_owner_info, new_quantity);
+        }
+    }
+
+    /// Sets the quantity left of a token listed using the Marketplace as an
+    /// operator of the owner, e.g. the balance of the owner after a sale.
+    /// Listings of the owner are trimmed to the quantity left.
+    pub fn update_operated_quantity(
+        &mut self,
+        token_owner_info: &TokenOwnerInfo,
+        quantity: ContractTokenAmount,
+    ) {
+        if self.tokens_operated.get(token_owner_info).is_some() {
+            if quantity.eq(&ContractTokenAmount::from(0)) {
+                self.tokens_operated.remove(token_owner_info);
+            } else {
+                self.tokens_operated
+                    .insert(token_owner_info.clone(), quantity);
+            }
+            self.cap_listings(token_owner_info, quantity);
         }
     }
 
@@ -225,45 +429,59 @@ where
             .ok_or(MarketplaceError::TokenNotInCustody)
     }
 
-    /// Gets a token from the buyable token list.
+    /// Gets the listing of a token by the owner at the given price from the
+    /// buyable token list.
     pub fn get_listed_token(
         &self,
         token_info: &TokenInfo,
         owner: &AccountAddress,
-    ) -> Result<(TokenRoyaltyState, Amount), MarketplaceError> {
-        match self.tokens_listed.get(token_info) {
-            Some(token) => match token.token_prices.get(owner) {
-                Some(price) => Ok((token.token_royalty, *price)),
-                None => Err(MarketplaceError::TokenNotListed),
-            },
-            None => Err(MarketplaceError::TokenNotListed),
-        }
+        price: Amount,
+    ) -> Result<(TokenRoyaltyState, TokenPriceState), MarketplaceError> {
+        let token = self
+            .tokens_listed
+            .get(token_info)
+            .ok_or(MarketplaceError::TokenNotListed)?;
+        let listings = token
+            .token_prices
+            .get(owner)
+            .ok_or(MarketplaceError::TokenNotListed)?;
+        let listing = listings
+            .iter()
+            .find(|l| l.price.eq(&price))
+            .ok_or(MarketplaceError::TokenNotListed)?;
+
+        Ok((token.token_royalty, *listing))
     }
 
     /// Gets a list of all tokens in the buyable token list.
+    /// Tokens in custody are skipped if the owner has listed the same token
+    /// using the Marketplace as an operator.
     pub fn get_listed_tokens(&self) -> Vec<TokenListItem> {
         self.tokens_owned
             .iter()
-            .filter_map(|owned_token| {
+            .filter(|owned_token| self.tokens_operated.get(&owned_token.0).is_none())
+            .chain(self.tokens_operated.iter())
+            .flat_map(|owned_token| {
                 match self.tokens_listed.get(&owned_token.0.to_owned().into()) {
-                    Some(listed_token) => {
-                        match listed_token
-                            .token_prices
-                            .get(&owned_token.0.owner.to_owned())
-                        {
-                            Some(price) => Some(TokenListItem {
+                    Some(listed_token) => match listed_token
+                        .token_prices
+                        .get(&owned_token.0.owner.to_owned())
+                    {
+                        Some(listings) => listings
+                            .iter()
+                            .map(|listing| TokenListItem {
                                 token_id: owned_token.0.id,
                                 contract: owned_token.0.address,
-                                price: *price,
+                                price: listing.price,
                                 owner: owned_token.0.owner,
                                 royalty: listed_token.token_royalty.royalty,
                                 primary_owner: listed_token.token_royalty.primary_owner,
-                                quantity: *owned_token.1,
-                            }),
-                            None => None,
-                        }
-                    }
-                    None => None,
+                                quantity: listing.quantity,
+                            })
+                            .collect(),
+                        None => Vec::new(),
+                    },
+                    None => Vec::new(),
                 }
             })
             .collect()
@@ -1,4 +1,7 @@
-use concordium_std::{ops::Mul, *};
+use concordium_std::{
+    ops::{Mul, Sub},
+    *,
+};
 
 use crate::{
     carbon_credit_market::{state::*, utils::distribute_amounts},
@@ -24,6 +27,10 @@ pub(crate) struct TransferParams {
 
     /// Quantity of the token to be transferred.
     pub quantity: ContractTokenAmount,
+
+    /// Price per unit of the listing being bought. An owner can list the same
+    /// token at several prices. Not used when the owner transfers the token.
+    pub price: Amount,
 }
 
 /// Allows for transferring the token specified by TransferParams.
@@ -31,6 +38,9 @@ pub(crate) struct TransferParams {
 /// This function is the typical buuy function of a Marketplace where one
 /// account can transfer an Asset by paying a price. The transfer will fail of
 /// the Amount paid is < token_quantity * token_price
+///
+/// Tokens in custody are transferred from the Marketplace. Tokens listed with
+/// the Marketplace as an operator are transferred directly from the owner.
 #[receive(
     contract = "carbon_credit_market",
     name = "transfer",
@@ -46,48 +56,79 @@ fn transfer<S: HasStateApi>(
     amount: Amount,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
+    ensure!(!host.state().paused, MarketplaceError::Paused);
     let params: TransferParams = ctx.parameter_cursor().get()?;
     let token_info = &TokenInfo {
         id: params.token_id,
         address: params.cis_contract_address,
     };
 
-    let quantity_owned = host.state().get_quantity_owned(token_info, &params.owner)?;
+    let token_owner_info = TokenOwnerInfo::from(token_info, &params.owner);
+    let listing_mode = host.state().get_listing_mode(&token_owner_info);
+    let quantity_owned = match listing_mode {
+        ListingMode::Custody => host.state().get_quantity_owned(token_info, &params.owner)?,
+        // The owner can move the tokens after listing them, so the balance is
+        // read from the CIS2 contract.
+        ListingMode::Operator => Client::cis2_get_balance(
+            host,
+            params.token_id,
+            &params.cis_contract_address,
+            Address::Account(params.owner),
+        )?,
+    };
 
     ensure!(
         quantity_owned.cmp(&params.quantity).is_ge(),
         MarketplaceError::InvalidTokenQuantity
     );
 
-    if ctx.sender() == Address::Account(params.owner) {
+    // Commission paid if the tokens are bought from a listing.
+    let commission = if ctx.sender() == Address::Account(params.owner) {
         ensure!(
             amount.cmp(&Amount::zero()).is_eq(),
             MarketplaceError::InvalidAmountPaid
         );
+        None
     } else {
-        let (token_royalty_state, price_per_unit) =
-            host.state().get_listed_token(token_info, &params.owner)?;
-        let price = price_per_unit.mul(params.quantity.into());
+        let (token_royalty_state, listing) =
+            host.state()
+                .get_listed_token(token_info, &params.owner, params.price)?;
+        ensure!(
+            listing.quantity.cmp(&params.quantity).is_ge(),
+            MarketplaceError::InvalidTokenQuantity
+        );
+        let price = listing.price.mul(params.quantity.into());
         ensure!(
             amount.cmp(&price).is_ge(),
             MarketplaceError::InvalidAmountPaid
         );
+        host.state_mut().decrease_listing_quantity(
+            &token_owner_info,
+            listing.price,
+            params.quantity,
+        )?;
 
-        distribute_amounts(
+        let commission_recipient = host.state().commission_recipient;
+        let commission = distribute_amounts(
             host,
             amount,
             &params.owner,
             &token_royalty_state,
-            &ctx.owner(),
+            &commission_recipient,
         )?;
+        Some(commission)
     };
 
+    let from = match listing_mode {
+        ListingMode::Custody => Address::Contract(ctx.self_address()),
+        ListingMode::Operator => Address::Account(params.owner),
+    };
     Client::cis2_transfer(
         host,
         params.token_id,
         params.cis_contract_address,
         params.quantity,
-        concordium_std::Address::Contract(ctx.self_address()),
+        from,
         concordium_cis2::Receiver::Account(params.to),
     )?;
 
@@ -98,10 +139,230 @@ fn transfer<S: HasStateApi>(
         to: Address::Account(params.to),
         amount: params.quantity,
     }))?;
+    if let Some(commission) = commission {
+        logger.log(&ContractEvent::TokenSold(TokenSoldEvent {
+            token_id: params.token_id,
+            token_contract: params.cis_contract_address,
+            seller: Address::Account(params.owner),
+            buyer: Address::Account(params.to),
+            amount: params.quantity,
+            paid: amount,
+            commission,
+        }))?;
+    }
 
-    host.state_mut().decrease_listed_quantity(
-        &TokenOwnerInfo::from(token_info, &params.owner),
-        params.quantity,
-    );
+    match listing_mode {
+        ListingMode::Custody => host
+            .state_mut()
+            .decrease_listed_quantity(&token_owner_info, params.quantity),
+        ListingMode::Operator => host
+            .state_mut()
+            .update_operated_quantity(&token_owner_info, quantity_owned.sub(params.quantity)),
+    }
     Ok(())
-}
\ No newline at end of file
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use concordium_cis2::{BalanceOfQueryResponse, SupportResult, SupportsQueryResponse};
+    use concordium_std::test_infrastructure::*;
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const SELLER: AccountAddress = AccountAddress([1u8; 32]);
+    const BUYER: AccountAddress = AccountAddress([2u8; 32]);
+    const FEE_RECIPIENT: AccountAddress = AccountAddress([3u8; 32]);
+    const CIS_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+    const MARKET_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 2,
+        subindex: 0,
+    };
+
+    /// A host where the seller listed 5 tokens at 1 CCD each, with the
+    /// Marketplace as an operator, while holding 10 tokens. The seller holds
+    /// `balance` tokens when they are bought.
+    fn host_with_operated_listing(balance: u64) -> TestHost<State<TestStateApi>> {
+        let token_info = TokenInfo {
+            id: ContractTokenId::from(1),
+            address: CIS_CONTRACT_ADDRESS,
+        };
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(
+            &mut state_builder,
+            ACCOUNT_0,
+            250,
+            vec![CIS_CONTRACT_ADDRESS],
+        );
+        state.add_operated_token(
+            &TokenOwnerInfo::from(&token_info, &SELLER),
+            ContractTokenAmount::from(10),
+        );
+        state.list_token(
+            &mut state_builder,
+            &token_info,
+            &SELLER,
+            Amount::from_ccd(1),
+            ContractTokenAmount::from(5),
+            0,
+        );
+
+        let mut host = TestHost::new(state, state_builder);
+        host.set_self_balance(Amount::from_ccd(2));
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(BALANCE_OF_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(move |_, _, _, _| {
+                Ok((
+                    false,
+                    BalanceOfQueryResponse(vec![ContractTokenAmount::from(balance)]),
+                ))
+            }),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(SUPPORTS_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|_, _, _, _| {
+                Ok((
+                    false,
+                    SupportsQueryResponse {
+                        results: vec![SupportResult::Support],
+                    },
+                ))
+            }),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(TRANSFER_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(()),
+        );
+        host
+    }
+
+    fn transfer_params() -> TransferParams {
+        TransferParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            token_id: ContractTokenId::from(1),
+            to: BUYER,
+            owner: SELLER,
+            quantity: ContractTokenAmount::from(2),
+            price: Amount::from_ccd(1),
+        }
+    }
+
+    #[concordium_test]
+    fn should_transfer_operated_token() {
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(Address::Account(BUYER));
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+        let parameter_bytes = to_bytes(&transfer_params());
+        ctx.set_parameter(&parameter_bytes);
+
+        // The seller moved some of the tokens away after listing them.
+        let mut host = host_with_operated_listing(4);
+        let mut logger = TestLogger::init();
+        let result = transfer(&ctx, &mut host, Amount::from_ccd(2), &mut logger);
+
+        assert_eq!(result, Ok(()));
+        let token_owner_info = TokenOwnerInfo {
+            id: ContractTokenId::from(1),
+            address: CIS_CONTRACT_ADDRESS,
+            owner: SELLER,
+        };
+        // The quantity left is the balance of the seller after the sale.
+        assert_eq!(
+            host.state()
+                .tokens_operated
+                .get(&token_owner_info)
+                .map(|q| *q),
+            Some(ContractTokenAmount::from(2))
+        );
+        assert_eq!(
+            host.state().get_listings(&token_owner_info),
+            vec![TokenPriceState {
+                quantity: ContractTokenAmount::from(2),
+                price: Amount::from_ccd(1),
+            }]
+        );
+        // The commission is 2.5% of 2 CCD.
+        assert_eq!(
+            logger.logs[1],
+            to_bytes(&ContractEvent::TokenSold(TokenSoldEvent {
+                token_id: ContractTokenId::from(1),
+                token_contract: CIS_CONTRACT_ADDRESS,
+                seller: Address::Account(SELLER),
+                buyer: Address::Account(BUYER),
+                amount: ContractTokenAmount::from(2),
+                paid: Amount::from_ccd(2),
+                commission: Amount::from_micro_ccd(50_000),
+            }))
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_log_sale_for_owner_transfer() {
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(Address::Account(SELLER));
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+        let parameter_bytes = to_bytes(&transfer_params());
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut host = host_with_operated_listing(10);
+        let mut logger = TestLogger::init();
+        let result = transfer(&ctx, &mut host, Amount::zero(), &mut logger);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(logger.logs.len(), 1);
+        assert!(host.get_transfers().is_empty());
+    }
+
+    #[concordium_test]
+    fn should_pay_commission_to_recipient() {
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(Address::Account(BUYER));
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+        let parameter_bytes = to_bytes(&transfer_params());
+        ctx.set_parameter(&parameter_bytes);
+
+        // The admin of the Marketplace is a contract, which cannot be paid
+        // with a transfer, so the commission goes to another account.
+        let mut host = host_with_operated_listing(10);
+        let admin = Address::Contract(ContractAddress {
+            index: 3,
+            subindex: 0,
+        });
+        host.state_mut().roles.propose_admin(admin);
+        host.state_mut().roles.accept_admin(&admin);
+        host.state_mut().commission_recipient = FEE_RECIPIENT;
+        let mut logger = TestLogger::init();
+        let result = transfer(&ctx, &mut host, Amount::from_ccd(2), &mut logger);
+
+        assert_eq!(result, Ok(()));
+        // The commission is 2.5% of 2 CCD.
+        assert_eq!(
+            host.get_transfers(),
+            vec![
+                (SELLER, Amount::from_micro_ccd(1_950_000)),
+                (FEE_RECIPIENT, Amount::from_micro_ccd(50_000)),
+            ]
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_transfer_more_than_seller_balance() {
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(Address::Account(BUYER));
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+        let parameter_bytes = to_bytes(&transfer_params());
+        ctx.set_parameter(&parameter_bytes);
+
+        // The seller holds fewer tokens than when they were listed.
+        let mut host = host_with_operated_listing(1);
+        let mut logger = TestLogger::init();
+        let result = transfer(&ctx, &mut host, Amount::from_ccd(2), &mut logger);
+
+        assert_eq!(result, Err(MarketplaceError::InvalidTokenQuantity));
+    }
+}
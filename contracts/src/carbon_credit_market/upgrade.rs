@@ -0,0 +1,125 @@
+use concordium_std::*;
+
+use crate::client_utils::upgrade::{self, UpgradeParams};
+
+use super::{contract_types::*, error::*};
+
+/// Upgrades the marketplace to a new module and calls the migration
+/// entrypoint of the new module, if any.
+///
+/// It rejects if:
+/// - The sender is not the admin.
+/// - It fails to parse the parameter.
+/// - The upgrade or the migration fails.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "upgrade",
+    parameter = "UpgradeParams",
+    error = "MarketplaceError",
+    low_level
+)]
+pub fn contract_upgrade<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<S, StateApiType = S>,
+) -> ContractResult<()> {
+    // The state is read with the layout of this module to authenticate the
+    // sender.
+    let state: ContractState<S> = host.state().read_root()?;
+    ensure!(
+        ctx.sender() == state.roles.admin(),
+        MarketplaceError::Unauthorized
+    );
+
+    let params: UpgradeParams = ctx.parameter_cursor().get()?;
+    upgrade::upgrade(&ctx.self_address(), host, params)?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        carbon_credit_market::state::{State, STATE_VERSION},
+        client_utils::upgrade::{test_utils::LowLevelTestHost, ContractUpgradeError},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const MARKET_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+
+    fn module() -> ModuleReference {
+        ModuleReference::new([1u8; 32])
+    }
+
+    fn upgrade_params(migrate: bool) -> UpgradeParams {
+        UpgradeParams {
+            module: module(),
+            migrate: migrate.then(|| {
+                (
+                    OwnedEntrypointName::new_unchecked("migrate".to_string()),
+                    OwnedParameter::empty(),
+                )
+            }),
+        }
+    }
+
+    #[concordium_test]
+    fn should_upgrade_and_migrate() {
+        let mut state_builder = TestStateBuilder::new();
+        let state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_upgrade(module(), Ok(()));
+        // The migration of the new module bumps the version of the state.
+        host.setup_mock_entrypoint(
+            MARKET_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked("migrate".to_string()),
+            MockFn::new_v1(|_, _, _, state: &mut State<TestStateApi>| {
+                state.version += 1;
+                Ok((true, ()))
+            }),
+        );
+        assert_eq!(host.state().version, STATE_VERSION);
+
+        let result = upgrade::upgrade(&MARKET_CONTRACT_ADDRESS, &mut host, upgrade_params(true));
+
+        assert!(result.is_ok());
+        assert_eq!(host.state().version, STATE_VERSION + 1);
+    }
+
+    #[concordium_test]
+    fn should_not_upgrade_to_missing_module() {
+        let mut state_builder = TestStateBuilder::new();
+        let state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_upgrade(module(), Err(UpgradeError::MissingModule));
+
+        let result = upgrade::upgrade(&MARKET_CONTRACT_ADDRESS, &mut host, upgrade_params(false));
+
+        assert_eq!(result, Err(ContractUpgradeError::FailedUpgrade));
+    }
+
+    #[concordium_test]
+    fn should_upgrade_by_admin_only() {
+        let mut host = LowLevelTestHost::new();
+        let state = State::new(host.state_builder(), ACCOUNT_0, 250, vec![]);
+        host.state_mut().write_root(&state);
+        host.host.setup_mock_upgrade(module(), Ok(()));
+
+        let parameter_bytes = to_bytes(&upgrade_params(false));
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        ctx.set_sender(Address::Account(AccountAddress([1u8; 32])));
+        let result = contract_upgrade(&ctx, &mut host);
+        assert_eq!(result, Err(MarketplaceError::Unauthorized));
+
+        ctx.set_sender(Address::Account(ACCOUNT_0));
+        let result = contract_upgrade(&ctx, &mut host);
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -9,13 +9,14 @@ pub struct DistributableAmounts {
 }
 
 // Distributes Selling Price, Royalty & Commission amounts.
+// Returns the Commission paid to the Marketplace.
 pub fn distribute_amounts<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     amount: Amount,
     token_owner: &AccountAddress,
     token_royalty_state: &TokenRoyaltyState,
-    marketplace_owner: &AccountAddress,
-) -> Result<(), MarketplaceError> {
+    commission_recipient: &AccountAddress,
+) -> Result<Amount, MarketplaceError> {
     let amounts = calculate_amounts(
         &amount,
         &host.state().commission,
@@ -28,7 +29,7 @@ pub fn distribute_amounts<S: HasStateApi>(
     }
 
     if amounts.to_marketplace.cmp(&Amount::zero()).is_gt() {
-        host.invoke_transfer(marketplace_owner, amounts.to_marketplace)
+        host.invoke_transfer(commission_recipient, amounts.to_marketplace)
             .map_err(|_| MarketplaceError::InvokeTransferError)?;
     }
 
@@ -37,7 +38,7 @@ pub fn distribute_amounts<S: HasStateApi>(
             .map_err(|_| MarketplaceError::InvokeTransferError)?;
     };
 
-    Ok(())
+    Ok(amounts.to_marketplace)
 }
 
 /// Calculates the amounts (Commission, Royalty & Selling Price) to be
@@ -50,15 +51,19 @@ pub fn calculate_amounts(
     let commission_amount =
         (*amount * commission.percentage_basis.into()).quotient_remainder(MAX_BASIS_POINTS.into());
 
-    let royalty_amount =
-        (*amount * royalty_percentage_basis.into()).quotient_remainder(MAX_BASIS_POINTS.into());
+    // The commission can be raised after a token is listed, in which case the
+    // royalty is limited to what is left after the commission.
+    let royalty_amount = (*amount * royalty_percentage_basis.into())
+        .quotient_remainder(MAX_BASIS_POINTS.into())
+        .0
+        .min(amount.subtract_micro_ccd(commission_amount.0.micro_ccd()));
 
     DistributableAmounts {
         to_seller: amount
             .subtract_micro_ccd(commission_amount.0.micro_ccd())
-            .subtract_micro_ccd(royalty_amount.0.micro_ccd()),
+            .subtract_micro_ccd(royalty_amount.micro_ccd()),
         to_marketplace: commission_amount.0,
-        to_primary_owner: royalty_amount.0,
+        to_primary_owner: royalty_amount,
     }
 }
 
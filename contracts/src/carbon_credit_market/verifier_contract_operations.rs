@@ -0,0 +1,141 @@
+use concordium_std::*;
+
+use crate::client_utils::{
+    roles::Role,
+    types::{VerifierContractParams, VerifierContractUpdatedEvent},
+};
+
+use super::{contract_types::*, error::*, events::*};
+
+/// Adds a contract from which incoming CIS2 transfers and listings are
+/// accepted.
+///
+/// It rejects if:
+/// - The sender is not an admin.
+/// - It fails to parse the parameter.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "addVerifierContract",
+    parameter = "VerifierContractParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn add_verifier_contract<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let VerifierContractParams { contract } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        MarketplaceError::Unauthorized
+    );
+
+    state.add_verifier_contract(contract);
+    logger.log(&ContractEvent::VerifierContractAdded(
+        VerifierContractUpdatedEvent { contract },
+    ))?;
+
+    Ok(())
+}
+
+/// Removes a verifier contract. Tokens of the contract which are already
+/// listed stay listed.
+///
+/// It rejects if:
+/// - The sender is not an admin.
+/// - It fails to parse the parameter.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "removeVerifierContract",
+    parameter = "VerifierContractParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn remove_verifier_contract<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let VerifierContractParams { contract } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        MarketplaceError::Unauthorized
+    );
+
+    state.remove_verifier_contract(&contract);
+    logger.log(&ContractEvent::VerifierContractRemoved(
+        VerifierContractUpdatedEvent { contract },
+    ))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::carbon_credit_market::{state::State, verifier_contracts::verifier_contracts};
+    use concordium_std::test_infrastructure::*;
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const ADMIN: Address = Address::Account(ACCOUNT_0);
+    const ACCOUNT_1: Address = Address::Account(AccountAddress([1u8; 32]));
+    const CIS_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+
+    #[concordium_test]
+    fn should_add_and_remove_verifier_contracts() {
+        let mut state_builder = TestStateBuilder::new();
+        let state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&VerifierContractParams {
+            contract: CIS_CONTRACT_ADDRESS,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        // Only an admin can add verifier contracts.
+        ctx.set_sender(ACCOUNT_1);
+        let result = add_verifier_contract(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::Unauthorized));
+
+        ctx.set_sender(ADMIN);
+        let result = add_verifier_contract(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert!(host.state().is_verifier_contract(&CIS_CONTRACT_ADDRESS));
+        assert_eq!(
+            verifier_contracts(&ctx, &host),
+            Ok(vec![CIS_CONTRACT_ADDRESS])
+        );
+
+        let result = remove_verifier_contract(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert!(!host.state().is_verifier_contract(&CIS_CONTRACT_ADDRESS));
+        assert_eq!(verifier_contracts(&ctx, &host), Ok(vec![]));
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::VerifierContractAdded(
+                    VerifierContractUpdatedEvent {
+                        contract: CIS_CONTRACT_ADDRESS
+                    }
+                )),
+                to_bytes(&ContractEvent::VerifierContractRemoved(
+                    VerifierContractUpdatedEvent {
+                        contract: CIS_CONTRACT_ADDRESS
+                    }
+                )),
+            ]
+        );
+    }
+}
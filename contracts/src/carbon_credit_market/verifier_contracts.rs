@@ -0,0 +1,25 @@
+use concordium_std::*;
+
+use crate::client_utils::types::VerifierContractsQueryResponse;
+
+use super::contract_types::*;
+
+/// Returns the contracts from which incoming CIS2 transfers and listings are
+/// accepted.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "verifierContracts",
+    return_value = "VerifierContractsQueryResponse",
+    error = "super::error::MarketplaceError"
+)]
+pub fn verifier_contracts<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<ContractState<S>, StateApiType = S>,
+) -> ContractResult<VerifierContractsQueryResponse> {
+    Ok(host
+        .state()
+        .verifier_contracts
+        .iter()
+        .map(|contract| *contract)
+        .collect())
+}
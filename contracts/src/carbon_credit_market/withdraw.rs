@@ -0,0 +1,235 @@
+use concordium_std::*;
+
+use super::{contract_types::*, error::*, events::*, state::*};
+use crate::client_utils::{client::Client, types::*};
+
+/// Parameters for the `withdraw` method for Market Contract.
+#[derive(Serial, Deserial, SchemaType)]
+pub(crate) struct WithdrawParams {
+    pub cis_contract_address: ContractAddress,
+    pub token_id: ContractTokenId,
+    /// Quantity of the token to be returned to the owner.
+    pub quantity: ContractTokenAmount,
+}
+
+/// Returns tokens held in custody by the Marketplace to the owner.
+/// If no tokens are left in custody the token is also delisted.
+///
+/// It rejects if:
+/// - Sender is a contract.
+/// - It fails to parse the parameter.
+/// - The token is not in custody of the Marketplace for the sender.
+/// - The quantity is zero or more than the quantity in custody.
+/// - The CIS2 transfer back to the owner fails.
+/// - Fails to log event.
+#[receive(
+    contract = "carbon_credit_market",
+    name = "withdraw",
+    parameter = "WithdrawParams",
+    mutable,
+    error = "MarketplaceError",
+    enable_logger
+)]
+pub fn withdraw<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<ContractState<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let sender_account_address: AccountAddress = match ctx.sender() {
+        Address::Account(account_address) => account_address,
+        Address::Contract(_) => bail!(MarketplaceError::CalledByAContract),
+    };
+
+    let params: WithdrawParams = ctx.parameter_cursor().get()?;
+    let token_info = TokenInfo {
+        id: params.token_id,
+        address: params.cis_contract_address,
+    };
+
+    let quantity_owned = host
+        .state()
+        .get_quantity_owned(&token_info, &sender_account_address)?;
+    ensure!(
+        params.quantity.cmp(&ContractTokenAmount::from(0)).is_gt()
+            && quantity_owned.cmp(&params.quantity).is_ge(),
+        MarketplaceError::InvalidTokenQuantity
+    );
+
+    // Update the custody before handing control to the CIS2 contract.
+    host.state_mut().decrease_listed_quantity(
+        &TokenOwnerInfo::from(&token_info, &sender_account_address),
+        params.quantity,
+    );
+
+    Client::cis2_transfer(
+        host,
+        params.token_id,
+        params.cis_contract_address,
+        params.quantity,
+        Address::Contract(ctx.self_address()),
+        concordium_cis2::Receiver::Account(sender_account_address),
+    )?;
+
+    logger.log(&ContractEvent::TokenWithdrawn(TokenWithdrawnEvent {
+        token_id: params.token_id,
+        token_contract: params.cis_contract_address,
+        owner: Address::Account(sender_account_address),
+        amount: params.quantity,
+    }))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::client_utils::client::{SUPPORTS_ENTRYPOINT_NAME, TRANSFER_ENTRYPOINT_NAME};
+    use concordium_cis2::{SupportResult, SupportsQueryResponse};
+    use concordium_std::test_infrastructure::*;
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const ADDRESS_0: Address = Address::Account(ACCOUNT_0);
+    const CIS_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+    const MARKET_CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 2,
+        subindex: 0,
+    };
+
+    fn host_with_owned_token(quantity: ContractTokenAmount) -> TestHost<State<TestStateApi>> {
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, ACCOUNT_0, 250, vec![]);
+        let token_info = TokenInfo {
+            address: CIS_CONTRACT_ADDRESS,
+            id: ContractTokenId::from(1),
+        };
+        state.add_owned_token(&TokenOwnerInfo::from(&token_info, &ACCOUNT_0), quantity);
+        state.list_token(
+            &mut state_builder,
+            &token_info,
+            &ACCOUNT_0,
+            Amount::from_ccd(1),
+            ContractTokenAmount::from(10),
+            0,
+        );
+
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(SUPPORTS_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|_, _, _, _| {
+                Ok((
+                    false,
+                    SupportsQueryResponse {
+                        results: vec![SupportResult::Support],
+                    },
+                ))
+            }),
+        );
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(TRANSFER_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(()),
+        );
+        host
+    }
+
+    #[concordium_test]
+    fn should_withdraw_token() {
+        let token_info = TokenInfo {
+            address: CIS_CONTRACT_ADDRESS,
+            id: ContractTokenId::from(1),
+        };
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let parameter_bytes = to_bytes(&WithdrawParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            token_id: ContractTokenId::from(1),
+            quantity: ContractTokenAmount::from(4),
+        });
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut host = host_with_owned_token(ContractTokenAmount::from(10));
+        let mut logger = TestLogger::init();
+
+        // Partial withdrawal keeps the listing.
+        let result = withdraw(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert_eq!(
+            host.state().get_quantity_owned(&token_info, &ACCOUNT_0),
+            Ok(ContractTokenAmount::from(6))
+        );
+        assert!(host
+            .state()
+            .get_listed_token(&token_info, &ACCOUNT_0, Amount::from_ccd(1))
+            .is_ok());
+
+        // Withdrawing more than is in custody fails.
+        let parameter_bytes = to_bytes(&WithdrawParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            token_id: ContractTokenId::from(1),
+            quantity: ContractTokenAmount::from(7),
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = withdraw(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(MarketplaceError::InvalidTokenQuantity));
+
+        // Withdrawing the rest removes the token from custody and delists it.
+        let parameter_bytes = to_bytes(&WithdrawParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            token_id: ContractTokenId::from(1),
+            quantity: ContractTokenAmount::from(6),
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = withdraw(&ctx, &mut host, &mut logger);
+        assert!(result.is_ok());
+        assert_eq!(
+            host.state().get_quantity_owned(&token_info, &ACCOUNT_0),
+            Err(MarketplaceError::TokenNotInCustody)
+        );
+        assert_eq!(
+            host.state()
+                .get_listed_token(&token_info, &ACCOUNT_0, Amount::from_ccd(1)),
+            Err(MarketplaceError::TokenNotListed)
+        );
+    }
+
+    #[concordium_test]
+    fn should_update_custody_before_transfer() {
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADDRESS_0);
+        ctx.set_self_address(MARKET_CONTRACT_ADDRESS);
+
+        let parameter_bytes = to_bytes(&WithdrawParams {
+            cis_contract_address: CIS_CONTRACT_ADDRESS,
+            token_id: ContractTokenId::from(1),
+            quantity: ContractTokenAmount::from(4),
+        });
+        ctx.set_parameter(&parameter_bytes);
+
+        let mut host = host_with_owned_token(ContractTokenAmount::from(10));
+        // The CIS2 contract sees the custody without the withdrawn tokens.
+        host.setup_mock_entrypoint(
+            CIS_CONTRACT_ADDRESS,
+            OwnedEntrypointName::new_unchecked(TRANSFER_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|_, _, _, state: &mut State<TestStateApi>| {
+                let token_info = TokenInfo {
+                    address: CIS_CONTRACT_ADDRESS,
+                    id: ContractTokenId::from(1),
+                };
+                assert_eq!(
+                    state.get_quantity_owned(&token_info, &ACCOUNT_0),
+                    Ok(ContractTokenAmount::from(6))
+                );
+                Ok((false, ()))
+            }),
+        );
+
+        let result = withdraw(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(result, Ok(()));
+    }
+}
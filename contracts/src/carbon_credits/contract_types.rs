@@ -3,7 +3,10 @@ use concordium_cis2::{
 };
 
 pub use crate::client_utils::types::ContractTokenId;
-use crate::client_utils::types::{MaturityOfQueryParams, IsVerifiedQueryParams, BurnParams, BurnParam};
+use crate::client_utils::types::{
+    BurnParam, BurnParams, IsVerifiedQueryParams, MaturityOfQueryParams, ProjectInfoQueryParams,
+    RetireForParams, RetireParams, RetirementCertificate, RetirementOfQueryResponse,
+};
 
 use super::error::ContractError;
 
@@ -20,6 +23,13 @@ pub type ContractBalanceOfQueryResponse = BalanceOfQueryResponse<ContractTokenAm
 
 pub type TransferParameter = TransferParams<ContractTokenId, ContractTokenAmount>;
 pub type ContractMaturityOfQueryParams = MaturityOfQueryParams<ContractTokenId>;
+pub type ContractProjectInfoQueryParams = ProjectInfoQueryParams<ContractTokenId>;
 pub type ContractIsVerifiedQueryParams = IsVerifiedQueryParams<ContractTokenId>;
 pub type ContractBurnParams = BurnParams<ContractTokenId, ContractTokenAmount>;
-pub type ContractBurnParam = BurnParam<ContractTokenId, ContractTokenAmount>;
\ No newline at end of file
+pub type ContractBurnParam = BurnParam<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetireParams = RetireParams<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetireForParams = RetireForParams<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetirementCertificate =
+    RetirementCertificate<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetirementOfQueryResponse =
+    RetirementOfQueryResponse<ContractTokenId, ContractTokenAmount>;
\ No newline at end of file
@@ -1,7 +1,9 @@
 use concordium_cis2::Cis2Error;
 use concordium_std::*;
 
-use crate::client_utils::errors::Cis2ClientError;
+use crate::client_utils::{
+    errors::Cis2ClientError, types::InvalidMetadataHash, upgrade::ContractUpgradeError,
+};
 
 pub type ContractError = Cis2Error<CustomContractError>;
 
@@ -28,7 +30,18 @@ pub enum CustomContractError {
     InvalidVerifierContract,          //-11
     TokenNotMature,                   //-12
     TokenNotVerified,                 //-13
-    TokenVerifiedOrMature
+    TokenVerifiedOrMature,            //-14
+    InvalidReportingPeriod,           //-15
+    InvalidCertificateId,             //-16
+    Paused,                           //-17
+    /// Upgrading the contract or migrating its state failed.
+    ContractUpgradeError(ContractUpgradeError), //-18
+    /// All token IDs have been allocated.
+    TokenIdsExhausted, //-19
+    /// Metadata hash is not a hex encoded SHA-256 hash.
+    InvalidMetadataHash, //-20
+    /// Metadata hash is required but missing.
+    MissingMetadataHash, //-21
 }
 
 /// Mapping the logging errors to ContractError.
@@ -72,3 +85,15 @@ impl From<Cis2ClientError> for ContractError {
         Cis2Error::Custom(CustomContractError::Cis2ClientError(cce))
     }
 }
+
+impl From<ContractUpgradeError> for ContractError {
+    fn from(ue: ContractUpgradeError) -> Self {
+        Cis2Error::Custom(CustomContractError::ContractUpgradeError(ue))
+    }
+}
+
+impl From<InvalidMetadataHash> for ContractError {
+    fn from(_: InvalidMetadataHash) -> Self {
+        Cis2Error::Custom(CustomContractError::InvalidMetadataHash)
+    }
+}
@@ -1,13 +1,31 @@
-use concordium_cis2::{MetadataUrl, MINT_EVENT_TAG, TOKEN_METADATA_EVENT_TAG, TRANSFER_EVENT_TAG, BURN_EVENT_TAG};
+use concordium_cis2::{
+    MetadataUrl, OperatorUpdate, UpdateOperatorEvent, BURN_EVENT_TAG, MINT_EVENT_TAG,
+    TOKEN_METADATA_EVENT_TAG, TRANSFER_EVENT_TAG, UPDATE_OPERATOR_EVENT_TAG,
+};
 use concordium_std::{collections::BTreeMap, schema::SchemaType, *};
 
-use super::contract_types::{ContractCollateralTokenAmount, ContractTokenAmount, ContractTokenId};
+use super::contract_types::{
+    ContractCollateralTokenAmount, ContractRetirementCertificate, ContractTokenAmount,
+    ContractTokenId,
+};
+use crate::client_utils::{
+    event_tags::{
+        ADMIN_PROPOSED_EVENT_TAG, ADMIN_TRANSFERRED_EVENT_TAG, COLLATERAL_ADDED_EVENT_TAG,
+        COLLATERAL_REMOVED_EVENT_TAG, COLLATERAL_USED_EVENT_TAG, PAUSED_EVENT_TAG,
+        RETIREMENT_CERTIFICATE_EVENT_TAG, RETIRE_EVENT_TAG, RETRACT_EVENT_TAG,
+        ROLE_GRANTED_EVENT_TAG, ROLE_REVOKED_EVENT_TAG, UNPAUSED_EVENT_TAG,
+        VERIFIER_CONTRACT_ADDED_EVENT_TAG, VERIFIER_CONTRACT_REMOVED_EVENT_TAG,
+    },
+    roles::{AdminProposedEvent, AdminTransferredEvent, Role, RoleUpdatedEvent},
+    types::{CertificateId, PauseEvent, RetirementDetails, VerifierContractUpdatedEvent},
+};
 pub type TransferEvent = concordium_cis2::TransferEvent<ContractTokenId, ContractTokenAmount>;
 pub type TokenMetadataEvent = concordium_cis2::TokenMetadataEvent<ContractTokenId>;
 pub type MintEvent = concordium_cis2::MintEvent<ContractTokenId, ContractTokenAmount>;
 pub type BurnEvent = concordium_cis2::BurnEvent<ContractTokenId, ContractTokenAmount>;
+pub type RetirementCertificateEvent = ContractRetirementCertificate;
 
-#[derive(Serial, SchemaType)]
+#[derive(Serial, Deserial, SchemaType)]
 pub struct CollateralUpdatedEvent {
     pub contract: ContractAddress,
     pub token_id: ContractTokenId,
@@ -19,20 +37,24 @@ pub enum ContractEvent {
     Mint(MintEvent),
     TokenMetadata(TokenMetadataEvent),
     Transfer(TransferEvent),
+    UpdateOperator(UpdateOperatorEvent),
     Retire(BurnEvent),
     Retract(BurnEvent),
     Burn(BurnEvent),
     CollateralAdded(CollateralUpdatedEvent),
     CollateralRemoved(CollateralUpdatedEvent),
-    CollateralUsedEvent(CollateralUpdatedEvent)
+    CollateralUsedEvent(CollateralUpdatedEvent),
+    RetirementCertificate(RetirementCertificateEvent),
+    RoleGranted(RoleUpdatedEvent),
+    RoleRevoked(RoleUpdatedEvent),
+    AdminProposed(AdminProposedEvent),
+    AdminTransferred(AdminTransferredEvent),
+    Paused(PauseEvent),
+    Unpaused(PauseEvent),
+    VerifierContractAdded(VerifierContractUpdatedEvent),
+    VerifierContractRemoved(VerifierContractUpdatedEvent),
 }
 
-const RETIRE_EVENT_TAG: u8 = u8::MIN;
-const COLLATERAL_ADDED_EVENT_TAG: u8 = u8::MIN + 1;
-const COLLATERAL_REMOVED_EVENT_TAG: u8 = u8::MIN + 2;
-const COLLATERAL_USED_EVENT_TAG: u8 = u8::MIN + 3;
-const RETRACT_EVENT_TAG: u8 = u8::MIN + 5;
-
 impl Serial for ContractEvent {
     fn serial<W: Write>(&self, out: &mut W) -> Result<(), W::Err> {
         match self {
@@ -48,6 +70,10 @@ impl Serial for ContractEvent {
                 out.write_u8(concordium_cis2::TOKEN_METADATA_EVENT_TAG)?;
                 event.serial(out)
             }
+            ContractEvent::UpdateOperator(event) => {
+                out.write_u8(UPDATE_OPERATOR_EVENT_TAG)?;
+                event.serial(out)
+            }
             ContractEvent::Retire(event) => {
                 out.write_u8(RETIRE_EVENT_TAG)?;
                 event.serial(out)
@@ -72,6 +98,75 @@ impl Serial for ContractEvent {
                 out.write_u8(COLLATERAL_USED_EVENT_TAG)?;
                 event.serial(out)
             }
+            ContractEvent::RetirementCertificate(event) => {
+                out.write_u8(RETIREMENT_CERTIFICATE_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::RoleGranted(event) => {
+                out.write_u8(ROLE_GRANTED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::RoleRevoked(event) => {
+                out.write_u8(ROLE_REVOKED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::AdminProposed(event) => {
+                out.write_u8(ADMIN_PROPOSED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::AdminTransferred(event) => {
+                out.write_u8(ADMIN_TRANSFERRED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::Paused(event) => {
+                out.write_u8(PAUSED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::Unpaused(event) => {
+                out.write_u8(UNPAUSED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::VerifierContractAdded(event) => {
+                out.write_u8(VERIFIER_CONTRACT_ADDED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::VerifierContractRemoved(event) => {
+                out.write_u8(VERIFIER_CONTRACT_REMOVED_EVENT_TAG)?;
+                event.serial(out)
+            }
+        }
+    }
+}
+
+impl Deserial for ContractEvent {
+    fn deserial<R: Read>(source: &mut R) -> ParseResult<Self> {
+        match source.read_u8()? {
+            TRANSFER_EVENT_TAG => Ok(ContractEvent::Transfer(source.get()?)),
+            MINT_EVENT_TAG => Ok(ContractEvent::Mint(source.get()?)),
+            UPDATE_OPERATOR_EVENT_TAG => Ok(ContractEvent::UpdateOperator(source.get()?)),
+            TOKEN_METADATA_EVENT_TAG => Ok(ContractEvent::TokenMetadata(source.get()?)),
+            RETIRE_EVENT_TAG => Ok(ContractEvent::Retire(source.get()?)),
+            RETRACT_EVENT_TAG => Ok(ContractEvent::Retract(source.get()?)),
+            BURN_EVENT_TAG => Ok(ContractEvent::Burn(source.get()?)),
+            COLLATERAL_ADDED_EVENT_TAG => Ok(ContractEvent::CollateralAdded(source.get()?)),
+            COLLATERAL_REMOVED_EVENT_TAG => Ok(ContractEvent::CollateralRemoved(source.get()?)),
+            COLLATERAL_USED_EVENT_TAG => Ok(ContractEvent::CollateralUsedEvent(source.get()?)),
+            RETIREMENT_CERTIFICATE_EVENT_TAG => {
+                Ok(ContractEvent::RetirementCertificate(source.get()?))
+            }
+            ROLE_GRANTED_EVENT_TAG => Ok(ContractEvent::RoleGranted(source.get()?)),
+            ROLE_REVOKED_EVENT_TAG => Ok(ContractEvent::RoleRevoked(source.get()?)),
+            ADMIN_PROPOSED_EVENT_TAG => Ok(ContractEvent::AdminProposed(source.get()?)),
+            ADMIN_TRANSFERRED_EVENT_TAG => Ok(ContractEvent::AdminTransferred(source.get()?)),
+            PAUSED_EVENT_TAG => Ok(ContractEvent::Paused(source.get()?)),
+            UNPAUSED_EVENT_TAG => Ok(ContractEvent::Unpaused(source.get()?)),
+            VERIFIER_CONTRACT_ADDED_EVENT_TAG => {
+                Ok(ContractEvent::VerifierContractAdded(source.get()?))
+            }
+            VERIFIER_CONTRACT_REMOVED_EVENT_TAG => {
+                Ok(ContractEvent::VerifierContractRemoved(source.get()?))
+            }
+            _ => Err(ParseError::default()),
         }
     }
 }
@@ -112,6 +207,17 @@ impl SchemaType for ContractEvent {
                 ]),
             ),
         );
+        event_map.insert(
+            UPDATE_OPERATOR_EVENT_TAG,
+            (
+                "UpdateOperator".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("update"), OperatorUpdate::get_type()),
+                    (String::from("owner"), Address::get_type()),
+                    (String::from("operator"), Address::get_type()),
+                ]),
+            ),
+        );
         event_map.insert(
             RETIRE_EVENT_TAG,
             (
@@ -190,6 +296,94 @@ impl SchemaType for ContractEvent {
                 ]),
             ),
         );
+        event_map.insert(
+            RETIREMENT_CERTIFICATE_EVENT_TAG,
+            (
+                "RetirementCertificate".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("certificate_id"), CertificateId::get_type()),
+                    (String::from("token_id"), ContractTokenId::get_type()),
+                    (String::from("amount"), ContractTokenAmount::get_type()),
+                    (String::from("owner"), Address::get_type()),
+                    (String::from("retired_at"), Timestamp::get_type()),
+                    (String::from("details"), RetirementDetails::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ROLE_GRANTED_EVENT_TAG,
+            (
+                "RoleGranted".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("address"), Address::get_type()),
+                    (String::from("role"), Role::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ROLE_REVOKED_EVENT_TAG,
+            (
+                "RoleRevoked".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("address"), Address::get_type()),
+                    (String::from("role"), Role::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ADMIN_PROPOSED_EVENT_TAG,
+            (
+                "AdminProposed".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("admin"), Address::get_type()),
+                    (String::from("proposed_admin"), Address::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ADMIN_TRANSFERRED_EVENT_TAG,
+            (
+                "AdminTransferred".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("previous_admin"), Address::get_type()),
+                    (String::from("admin"), Address::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            PAUSED_EVENT_TAG,
+            (
+                "Paused".to_string(),
+                schema::Fields::Named(vec![(String::from("pauser"), Address::get_type())]),
+            ),
+        );
+        event_map.insert(
+            UNPAUSED_EVENT_TAG,
+            (
+                "Unpaused".to_string(),
+                schema::Fields::Named(vec![(String::from("pauser"), Address::get_type())]),
+            ),
+        );
+        event_map.insert(
+            VERIFIER_CONTRACT_ADDED_EVENT_TAG,
+            (
+                "VerifierContractAdded".to_string(),
+                schema::Fields::Named(vec![(
+                    String::from("contract"),
+                    ContractAddress::get_type(),
+                )]),
+            ),
+        );
+        event_map.insert(
+            VERIFIER_CONTRACT_REMOVED_EVENT_TAG,
+            (
+                "VerifierContractRemoved".to_string(),
+                schema::Fields::Named(vec![(
+                    String::from("contract"),
+                    ContractAddress::get_type(),
+                )]),
+            ),
+        );
         schema::Type::TaggedEnum(event_map)
     }
 }
@@ -5,6 +5,8 @@ use super::state::State;
 #[derive(Serial, Deserial, SchemaType)]
 pub struct InitParams {
     pub verifier_contracts: Vec<ContractAddress>,
+    /// Whether the metadata url of a token must have a hash.
+    pub metadata_hash_required: bool,
 }
 
 // Contract functions
@@ -21,6 +23,12 @@ fn contract_init<S: HasStateApi>(
 ) -> InitResult<State<S>> {
     // Parse the parameter.
     let params: InitParams = ctx.parameter_cursor().get()?;
-    // Construct the initial contract state.
-    Ok(State::new(state_builder, params.verifier_contracts))
+    // Construct the initial contract state. The account creating the contract
+    // is its admin.
+    Ok(State::new(
+        state_builder,
+        Address::Account(ctx.init_origin()),
+        params.verifier_contracts,
+        params.metadata_hash_required,
+    ))
 }
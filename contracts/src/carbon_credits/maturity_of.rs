@@ -8,7 +8,8 @@ use super::{
     state::State,
 };
 
-/// Returns the maturity time of the given tokens.
+/// Returns the maturity schedule of the given tokens, which is the schedule
+/// of the project token they are collateralized by.
 #[receive(
     contract = "carbon_credits",
     name = "maturityOf",
@@ -31,8 +32,9 @@ pub fn maturity_of<S: HasStateApi>(
             .find_collateral(token_id)
             .ok_or(ContractError::Custom(CustomContractError::InvalidCollateral))?;
 
-        let time = Client::maturity_of(host, collateral_token.token_id, collateral_token.contract)?;
-        res.push(time);
+        let maturity =
+            Client::maturity_of(host, collateral_token.token_id, collateral_token.contract)?;
+        res.push(maturity);
     }
 
     Ok(res)
@@ -1,8 +1,16 @@
 use concordium_std::*;
 
-use crate::client_utils::types::{ContractMetadataUrl, ContractTokenAmount, ContractTokenId};
+use crate::client_utils::{
+    roles::Role,
+    types::{ContractMetadataUrl, ContractTokenAmount, ContractTokenId},
+};
 
-use super::{contract_types::ContractResult, error::CustomContractError, events::*, state::*};
+use super::{
+    contract_types::ContractResult,
+    error::{ContractError, CustomContractError},
+    events::*,
+    state::*,
+};
 
 #[derive(Serial, Deserial, SchemaType)]
 pub struct TokenMintParams {
@@ -25,20 +33,23 @@ pub struct MintParams {
 }
 
 /// Mint new tokens with a given address as the owner of these tokens.
-/// Can only be called by the contract owner.
-/// Logs a `Mint` and a `TokenMetadata` event for each token.
+/// Can only be called by an address with the `Minter` role.
+/// Logs a `CollateralUsed`, a `Mint` and a `TokenMetadata` event for each
+/// token.
 /// The url for the token metadata is the token ID encoded in hex, appended on
 /// the `TOKEN_METADATA_BASE_URL`.
 ///
 /// It rejects if:
-/// - The sender is not the contract instance owner.
+/// - The sender does not have the `Minter` role.
 /// - Fails to parse parameter.
 /// - Any of the tokens fails to be minted, which could be if:
+///     - The hash of its metadata url is malformed, or missing while hashes
+///       are required.
 ///     - Fails to log Mint event.
 ///     - Fails to log TokenMetadata event.
 ///
-/// Note: Can at most mint 32 token types in one call due to the limit on the
-/// number of logs a smart contract can produce on each function call.
+/// Note: Can at most mint 21 token types in one call due to the limit of 64
+/// logs a smart contract can produce on each function call.
 #[receive(
     contract = "carbon_credits",
     name = "mint",
@@ -52,6 +63,8 @@ pub fn mint<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(!host.state().paused, CustomContractError::Paused.into());
     let sender = match ctx.sender() {
         Address::Account(a) => a,
         Address::Contract(_) => bail!(CustomContractError::AccountsOnly.into()),
@@ -61,6 +74,11 @@ pub fn mint<S: HasStateApi>(
     let params: MintParams = ctx.parameter_cursor().get()?;
 
     let (state, builder) = host.state_and_builder();
+    // Only minters can mint credits against their collateral.
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Minter),
+        ContractError::Unauthorized
+    );
     for token_info in params.tokens {
         ensure!(
             state.has_unused_collateral(&CollateralToken {
@@ -72,12 +90,13 @@ pub fn mint<S: HasStateApi>(
         );
 
         // Mint the token in the state.
+        let metadata_url = state.metadata_url(token_info.metadata)?;
         let token_id = state.mint(
-            &token_info.metadata,
+            metadata_url.clone(),
             token_info.amount,
             &params.owner,
             builder,
-        );
+        )?;
 
         let collateral_amount = state.use_collateral(
             &CollateralToken {
@@ -105,9 +124,85 @@ pub fn mint<S: HasStateApi>(
         logger.log(&ContractEvent::TokenMetadata(
             super::events::TokenMetadataEvent {
                 token_id,
-                metadata_url: token_info.metadata.into(),
+                metadata_url,
             },
         ))?;
     }
     Ok(())
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::carbon_credits::state::test_utils::*;
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_mint_by_minter_only() {
+        let mut state_builder = TestStateBuilder::new();
+        let mut state = State::new(&mut state_builder, ADMIN, vec![PROJECT_CONTRACT], false);
+        state.add_collateral(PROJECT_CONTRACT, PROJECT_TOKEN_ID, OWNER_ACCOUNT, 1.into());
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&MintParams {
+            owner: OWNER,
+            tokens: vec![TokenMintParams {
+                metadata: ContractMetadataUrl {
+                    url: "https://example.com/credit/1.json".to_string(),
+                    hash: None,
+                },
+                amount: 100.into(),
+                contract: PROJECT_CONTRACT,
+                token_id: PROJECT_TOKEN_ID,
+            }],
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+
+        let result = mint(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        // Once granted the role the owner of the collateral can mint.
+        let (state, builder) = host.state_and_builder();
+        state.roles.grant(&OWNER, Role::Minter, builder);
+        let result = mint(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().balance(&0.into(), &OWNER), Ok(100.into()));
+        assert_eq!(host.state().get_supply(&0.into()), 100.into());
+
+        // A revoked minter can no longer mint.
+        host.state_mut().roles.revoke(&OWNER, Role::Minter);
+        host.state_mut()
+            .add_collateral(PROJECT_CONTRACT, 8.into(), OWNER_ACCOUNT, 1.into());
+        let parameter_bytes = to_bytes(&MintParams {
+            owner: OWNER,
+            tokens: vec![TokenMintParams {
+                metadata: ContractMetadataUrl {
+                    url: "https://example.com/credit/2.json".to_string(),
+                    hash: None,
+                },
+                amount: 100.into(),
+                contract: PROJECT_CONTRACT,
+                token_id: 8.into(),
+            }],
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = mint(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert!(!host.state().contains_token(&1.into()));
+    }
+
+    #[concordium_test]
+    fn should_not_mint_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder, 100);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::default();
+        let result = mint(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(result, Err(CustomContractError::Paused.into()));
+    }
+}
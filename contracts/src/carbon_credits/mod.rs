@@ -12,7 +12,14 @@ pub mod supports;
 pub mod on_cis2_recieved;
 pub mod events;
 pub mod maturity_of;
+pub mod project_info;
 pub mod is_verified;
 pub mod retire;
+pub mod retirement_of;
 pub mod retract;
 pub mod is_verifier;
+pub mod role_operations;
+pub mod pause;
+pub mod upgrade;
+pub mod verifier_contract_operations;
+pub mod verifier_contracts;
@@ -49,7 +49,7 @@ fn on_cis2_received<S: HasStateApi>(
     };
 
     let is_verified: bool = Client::is_verified(host, params.token_id, sender)?;
-    // Ensure the token is verified.
+    // Ensure the token is verified by a quorum of verifiers.
     ensure!(is_verified, CustomContractError::InvalidCollateral.into());
 
     host.state_mut()
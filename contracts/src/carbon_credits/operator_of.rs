@@ -17,15 +17,16 @@ use super::{contract_types::*, state::*};
 )]
 fn operator_of<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    _host: &impl HasHost<State<S>, StateApiType = S>,
+    host: &impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<OperatorOfQueryResponse> {
     // Parse the parameter.
     let params: OperatorOfQueryParams = ctx.parameter_cursor().get()?;
     // Build the response.
     let mut response = Vec::with_capacity(params.queries.len());
-    for _query in params.queries {
+    for query in params.queries {
         // Query the state for address being an operator of owner.
-        response.push(false);
+        let is_operator = host.state().is_operator(&query.address, &query.owner);
+        response.push(is_operator);
     }
     let result = OperatorOfQueryResponse::from(response);
     Ok(result)
@@ -0,0 +1,109 @@
+use concordium_std::*;
+
+use crate::client_utils::{client::Client, types::ProjectInfoQueryResponse};
+
+use super::{
+    contract_types::{ContractProjectInfoQueryParams, ContractResult},
+    error::*,
+    state::State,
+};
+
+/// Returns the registry information of the given tokens, which they inherit
+/// from the project token they are collateralized by. The information is
+/// queried from the project token contract instead of being copied at mint,
+/// so it stays the single source of truth.
+#[receive(
+    contract = "carbon_credits",
+    name = "projectInfo",
+    parameter = "ContractProjectInfoQueryParams",
+    return_value = "ProjectInfoQueryResponse",
+    error = "ContractError"
+)]
+pub fn project_info<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ProjectInfoQueryResponse> {
+    // Parse the parameter.
+    let params: ContractProjectInfoQueryParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    let mut res = Vec::with_capacity(params.queries.len());
+
+    for token_id in params.queries.iter() {
+        ensure!(
+            state.contains_token(token_id),
+            ContractError::InvalidTokenId
+        );
+        let (collateral_token, _) =
+            state
+                .find_collateral(token_id)
+                .ok_or(ContractError::Custom(
+                    CustomContractError::InvalidCollateral,
+                ))?;
+
+        let project_info =
+            Client::project_info(host, collateral_token.token_id, collateral_token.contract)?;
+        res.push(project_info);
+    }
+
+    Ok(res)
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        carbon_credits::{contract_types::ContractTokenId, state::test_utils::*},
+        client_utils::{
+            client::PROJECT_INFO_ENTRYPOINT_NAME,
+            types::{ProjectInfo, SerialRange},
+        },
+    };
+    use concordium_std::test_infrastructure::*;
+
+    fn registry_info() -> ProjectInfo {
+        ProjectInfo {
+            vintage: 2023,
+            registry: "Verra".to_string(),
+            serial_range: SerialRange {
+                start: "1".to_string(),
+                end: "100".to_string(),
+            },
+            methodology: "VM0015".to_string(),
+            country: "BR".to_string(),
+            project_type: "Reforestation".to_string(),
+        }
+    }
+
+    #[concordium_test]
+    fn should_query_project_info_of_collateral() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_entrypoint(
+            PROJECT_CONTRACT,
+            OwnedEntrypointName::new_unchecked(PROJECT_INFO_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(|parameter, _, _, _| {
+                let params: ContractProjectInfoQueryParams =
+                    from_bytes(parameter.as_ref()).expect_report("Valid parameter");
+                assert_eq!(params.queries, vec![PROJECT_TOKEN_ID]);
+                Ok((false, vec![registry_info()]))
+            }),
+        );
+
+        let parameter_bytes = to_bytes(&ContractProjectInfoQueryParams {
+            queries: vec![token_id],
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        let result = project_info(&ctx, &host);
+        assert_eq!(result, Ok(vec![registry_info()]));
+
+        let parameter_bytes = to_bytes(&ContractProjectInfoQueryParams {
+            queries: vec![ContractTokenId::from(1)],
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = project_info(&ctx, &host);
+        assert_eq!(result, Err(ContractError::InvalidTokenId));
+    }
+}
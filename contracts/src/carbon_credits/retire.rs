@@ -1,13 +1,16 @@
 use concordium_std::*;
 
-use crate::client_utils::client::Client;
+use crate::client_utils::{
+    client::Client,
+    types::{Beneficiary, RetirementDetails},
+};
 
 use super::{contract_types::*, error::*, events::*, state::*};
 
 #[receive(
     contract = "carbon_credits",
     name = "retire",
-    parameter = "ContractBurnParams",
+    parameter = "ContractRetireParams",
     error = "ContractError",
     enable_logger,
     mutable
@@ -17,31 +20,91 @@ fn retire<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
-    let ContractBurnParams { tokens, owner } = ctx.parameter_cursor().get()?;
+    let ContractRetireParams {
+        tokens,
+        owner,
+        details,
+    } = ctx.parameter_cursor().get()?;
     ensure!(ctx.sender() == owner, ContractError::Unauthorized);
 
+    retire_tokens(ctx, host, logger, owner, tokens, details)
+}
+
+/// Retires the tokens of the owner with the retirement attributed to the
+/// beneficiary. Can be called by the owner or an operator of the owner.
+#[receive(
+    contract = "carbon_credits",
+    name = "retireFor",
+    parameter = "ContractRetireForParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+fn retire_for<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let ContractRetireForParams {
+        owner,
+        tokens,
+        beneficiary,
+        reason,
+        reporting_period,
+    } = ctx.parameter_cursor().get()?;
+    let sender = ctx.sender();
+    ensure!(
+        sender == owner || host.state().is_operator(&sender, &owner),
+        ContractError::Unauthorized
+    );
+
+    let details = RetirementDetails {
+        beneficiary: Beneficiary::Address(beneficiary),
+        reason,
+        reporting_period,
+    };
+    retire_tokens(ctx, host, logger, owner, tokens, details)
+}
+
+fn retire_tokens<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+    owner: Address,
+    tokens: Vec<ContractBurnParam>,
+    details: RetirementDetails,
+) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(!host.state().paused, CustomContractError::Paused.into());
+    ensure!(
+        details.reporting_period.start <= details.reporting_period.end,
+        CustomContractError::InvalidReportingPeriod.into()
+    );
+
     for ContractBurnParam { token_id, amount } in tokens {
         let state = host.state();
-        let (is_mature, is_verified) = {
+        let (matured_fraction, is_verified) = {
             // Get Collateral Token Info
             let (collateral_key, _) = state
                 .find_collateral(&token_id)
                 .ok_or(CustomContractError::InvalidCollateral)?;
 
-            // Get Maturity Time
+            // Get Maturity Schedule
             let maturity_of =
                 Client::maturity_of(host, collateral_key.token_id, collateral_key.contract)?;
-            let is_mature = maturity_of <= ctx.metadata().slot_time();
+            let matured_fraction = maturity_of.matured_fraction(&ctx.metadata().slot_time());
 
-            // Get Verification Status
-            let is_verified = Client::is_verified(host, token_id, collateral_key.contract)?;
+            // Get Verification Status of the project, which is verified only once the
+            // quorum of verifiers is met.
+            let is_verified =
+                Client::is_verified(host, collateral_key.token_id, collateral_key.contract)?;
 
-            (is_mature, is_verified)
+            (matured_fraction, is_verified)
         };
 
-        // ensure is mature
+        // ensure only the matured fraction of the minted tokens is retired
         ensure!(
-            is_mature,
+            state.retirable_amount(&token_id, matured_fraction) >= amount,
             CustomContractError::TokenNotMature.into()
         );
 
@@ -53,6 +116,13 @@ fn retire<S: HasStateApi>(
 
         // burn the tokens
         host.state_mut().burn(&token_id, amount, &owner);
+        let certificate = host.state_mut().add_retirement(
+            token_id,
+            amount,
+            owner,
+            ctx.metadata().slot_time(),
+            details.clone(),
+        );
 
         // log Retire event
         logger.log(&ContractEvent::Retire(BurnEvent {
@@ -67,7 +137,260 @@ fn retire<S: HasStateApi>(
             amount,
             owner,
         }))?;
+
+        // log RetirementCertificate event
+        logger.log(&ContractEvent::RetirementCertificate(certificate))?;
     }
 
     Ok(())
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        carbon_credits::state::test_utils::*,
+        client_utils::types::{BurnParam, ReportingPeriod, MATURITY_BASIS_POINTS},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    const RETIRED_AT: u64 = 1000;
+
+    fn details() -> RetirementDetails {
+        RetirementDetails {
+            beneficiary: Beneficiary::Name("ACME Ltd".to_string()),
+            reason: "Offsetting 2023 business travel".to_string(),
+            reporting_period: ReportingPeriod {
+                start: Timestamp::from_timestamp_millis(1),
+                end: Timestamp::from_timestamp_millis(2),
+            },
+        }
+    }
+
+    #[concordium_test]
+    fn should_retire_with_certificates() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        setup_project_mocks(&mut host, maturity(&[(0, MATURITY_BASIS_POINTS)]), true);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&ContractRetireParams {
+            owner: OWNER,
+            tokens: vec![
+                BurnParam {
+                    token_id,
+                    amount: 10.into(),
+                },
+                BurnParam {
+                    token_id,
+                    amount: 5.into(),
+                },
+            ],
+            details: details(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(RETIRED_AT));
+
+        let result = retire(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+
+        // Every retirement gets the next certificate id.
+        let certificate = |certificate_id, amount: u64| ContractRetirementCertificate {
+            certificate_id,
+            token_id,
+            amount: amount.into(),
+            owner: OWNER,
+            retired_at: Timestamp::from_timestamp_millis(RETIRED_AT),
+            details: details(),
+        };
+        assert_eq!(host.state().next_certificate_id, 2);
+        assert_eq!(host.state().get_retirement(&0), Some(certificate(0, 10)));
+        assert_eq!(host.state().get_retirement(&1), Some(certificate(1, 5)));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(85.into()));
+
+        let retire_events = |amount: u64| {
+            let event = || BurnEvent {
+                token_id,
+                amount: amount.into(),
+                owner: OWNER,
+            };
+            vec![
+                to_bytes(&ContractEvent::Retire(event())),
+                to_bytes(&ContractEvent::Burn(event())),
+            ]
+        };
+        assert_eq!(
+            logger.logs,
+            [
+                retire_events(10),
+                vec![to_bytes(&ContractEvent::RetirementCertificate(
+                    certificate(0, 10)
+                ))],
+                retire_events(5),
+                vec![to_bytes(&ContractEvent::RetirementCertificate(
+                    certificate(1, 5)
+                ))],
+            ]
+            .concat()
+        );
+    }
+
+    #[concordium_test]
+    fn should_retire_matured_fraction_only() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        // 40% matures at 0 and the rest at 2000.
+        setup_project_mocks(&mut host, maturity(&[(0, 4000), (2000, 6000)]), true);
+
+        let retire_at = |host: &mut TestHost<State<TestStateApi>>, time, amount: u64| {
+            let parameter_bytes = to_bytes(&ContractRetireParams {
+                owner: OWNER,
+                tokens: vec![BurnParam {
+                    token_id,
+                    amount: amount.into(),
+                }],
+                details: details(),
+            });
+            let mut ctx = TestReceiveContext::default();
+            ctx.set_parameter(&parameter_bytes);
+            ctx.set_sender(OWNER);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(time));
+            retire(&ctx, host, &mut TestLogger::init())
+        };
+
+        assert_eq!(
+            retire_at(&mut host, RETIRED_AT, 41),
+            Err(CustomContractError::TokenNotMature.into())
+        );
+        assert_eq!(retire_at(&mut host, RETIRED_AT, 30), Ok(()));
+        // Earlier retirements count against the matured amount.
+        assert_eq!(
+            retire_at(&mut host, RETIRED_AT, 15),
+            Err(CustomContractError::TokenNotMature.into())
+        );
+        assert_eq!(retire_at(&mut host, 2000, 15), Ok(()));
+
+        let state = host.state();
+        assert_eq!(
+            state.minted_supply.get(&token_id).map(|a| *a),
+            Some(100.into())
+        );
+        assert_eq!(
+            state.retired_supply.get(&token_id).map(|a| *a),
+            Some(45.into())
+        );
+        assert_eq!(state.get_supply(&token_id), 55.into());
+        assert_eq!(state.balance(&token_id, &OWNER), Ok(55.into()));
+    }
+
+    #[concordium_test]
+    fn should_not_retire_tokens_of_others() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        setup_project_mocks(&mut host, maturity(&[(0, MATURITY_BASIS_POINTS)]), true);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&ContractRetireParams {
+            owner: OWNER,
+            tokens: vec![BurnParam {
+                token_id,
+                amount: 10.into(),
+            }],
+            details: details(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(ADMIN);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(RETIRED_AT));
+
+        let result = retire(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(host.state().next_certificate_id, 0);
+    }
+
+    #[concordium_test]
+    fn should_retire_for_beneficiary_by_operator() {
+        let operator = Address::Account(AccountAddress([2u8; 32]));
+        let beneficiary = Address::Account(AccountAddress([3u8; 32]));
+
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        setup_project_mocks(&mut host, maturity(&[(0, MATURITY_BASIS_POINTS)]), true);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&ContractRetireForParams {
+            owner: OWNER,
+            tokens: vec![BurnParam {
+                token_id,
+                amount: 10.into(),
+            }],
+            beneficiary,
+            reason: details().reason,
+            reporting_period: details().reporting_period,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(operator);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(RETIRED_AT));
+
+        // The sender is not an operator of the owner.
+        let result = retire_for(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(100.into()));
+
+        let (state, builder) = host.state_and_builder();
+        state.add_operator(&OWNER, &operator, builder);
+        let result = retire_for(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(90.into()));
+
+        // The retirement is by the owner, on behalf of the beneficiary.
+        let certificate = ContractRetirementCertificate {
+            certificate_id: 0,
+            token_id,
+            amount: 10.into(),
+            owner: OWNER,
+            retired_at: Timestamp::from_timestamp_millis(RETIRED_AT),
+            details: RetirementDetails {
+                beneficiary: Beneficiary::Address(beneficiary),
+                ..details()
+            },
+        };
+        assert_eq!(host.state().get_retirement(&0), Some(certificate.clone()));
+        assert_eq!(
+            logger.logs.last(),
+            Some(&to_bytes(&ContractEvent::RetirementCertificate(
+                certificate
+            )))
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_retire_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder, 100);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&ContractRetireParams {
+            owner: OWNER,
+            tokens: vec![BurnParam {
+                token_id,
+                amount: 1.into(),
+            }],
+            details: details(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+
+        let result = retire(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(result, Err(CustomContractError::Paused.into()));
+    }
+}
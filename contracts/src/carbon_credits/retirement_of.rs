@@ -0,0 +1,86 @@
+use concordium_std::*;
+
+use crate::client_utils::types::RetirementOfQueryParams;
+
+use super::{
+    contract_types::{ContractResult, ContractRetirementOfQueryResponse},
+    error::*,
+    state::State,
+};
+
+/// Returns the certificates of the given retirements.
+#[receive(
+    contract = "carbon_credits",
+    name = "retirementOf",
+    parameter = "RetirementOfQueryParams",
+    return_value = "ContractRetirementOfQueryResponse",
+    error = "ContractError"
+)]
+pub fn retirement_of<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ContractRetirementOfQueryResponse> {
+    // Parse the parameter.
+    let params: RetirementOfQueryParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    params
+        .queries
+        .iter()
+        .map(|certificate_id| {
+            state
+                .get_retirement(certificate_id)
+                .ok_or(ContractError::Custom(
+                    CustomContractError::InvalidCertificateId,
+                ))
+        })
+        .collect()
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        carbon_credits::state::test_utils::*,
+        client_utils::types::{Beneficiary, ReportingPeriod, RetirementDetails},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_query_retirements() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder, 100);
+        let certificate = state.add_retirement(
+            token_id,
+            10.into(),
+            OWNER,
+            Timestamp::from_timestamp_millis(1000),
+            RetirementDetails {
+                beneficiary: Beneficiary::Address(OWNER),
+                reason: "Offsetting 2023 business travel".to_string(),
+                reporting_period: ReportingPeriod {
+                    start: Timestamp::from_timestamp_millis(1),
+                    end: Timestamp::from_timestamp_millis(2),
+                },
+            },
+        );
+        let host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&RetirementOfQueryParams { queries: vec![0] });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        let result = retirement_of(&ctx, &host);
+        assert_eq!(result, Ok(vec![certificate]));
+
+        // Queries of unknown certificates fail.
+        let parameter_bytes = to_bytes(&RetirementOfQueryParams {
+            queries: vec![0, 1],
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = retirement_of(&ctx, &host);
+        assert_eq!(
+            result,
+            Err(CustomContractError::InvalidCertificateId.into())
+        );
+    }
+}
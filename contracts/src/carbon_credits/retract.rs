@@ -17,6 +17,8 @@ fn retract<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(!host.state().paused, CustomContractError::Paused.into());
     let ContractBurnParams { owner, tokens } = ctx.parameter_cursor().get()?;
     let sender = ctx.sender();
     let is_verifier = host
@@ -45,12 +47,13 @@ fn retract<S: HasStateApi>(
                 .find_collateral(&token_id)
                 .ok_or(CustomContractError::InvalidCollateral)?;
 
-            // Get Maturity Time
+            // Get Maturity Schedule
             let maturity_of =
                 Client::maturity_of(host, collateral_key.token_id, collateral_key.contract)?;
-            let is_mature = maturity_of <= ctx.metadata().slot_time();
+            let is_mature = maturity_of.is_mature(&ctx.metadata().slot_time());
             // Get Verification Status
-            let is_verified = Client::is_verified(host, token_id, collateral_key.contract)?;
+            let is_verified =
+                Client::is_verified(host, collateral_key.token_id, collateral_key.contract)?;
 
             (is_mature, is_verified)
         };
@@ -83,3 +86,109 @@ fn retract<S: HasStateApi>(
 
     Ok(())
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        carbon_credits::state::test_utils::*,
+        client_utils::{client::IS_VERIFIER_ENTRYPOINT_NAME, types::MATURITY_BASIS_POINTS},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    fn retract_params(token_id: ContractTokenId) -> ContractBurnParams {
+        ContractBurnParams {
+            owner: OWNER,
+            tokens: vec![BurnParam {
+                token_id,
+                amount: 10.into(),
+            }],
+        }
+    }
+
+    /// A host with the project token mocked, where the owner is no verifier.
+    fn host_with_project(
+        state: State<TestStateApi>,
+        state_builder: TestStateBuilder,
+        is_verified: bool,
+    ) -> TestHost<State<TestStateApi>> {
+        let mut host = TestHost::new(state, state_builder);
+        setup_project_mocks(
+            &mut host,
+            maturity(&[(0, MATURITY_BASIS_POINTS)]),
+            is_verified,
+        );
+        host.setup_mock_entrypoint(
+            PROJECT_CONTRACT,
+            OwnedEntrypointName::new_unchecked(IS_VERIFIER_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(vec![false]),
+        );
+        host
+    }
+
+    #[concordium_test]
+    fn should_not_retract_verified_mature_token() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        // The verification is looked up by the id of the collateral project
+        // token, not by the id of the carbon credit.
+        assert_ne!(token_id, PROJECT_TOKEN_ID);
+        let mut host = host_with_project(state, state_builder, true);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&retract_params(token_id));
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(1000));
+
+        let result = retract(&ctx, &mut host, &mut logger);
+        assert_eq!(
+            result,
+            Err(CustomContractError::TokenVerifiedOrMature.into())
+        );
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(100.into()));
+    }
+
+    #[concordium_test]
+    fn should_retract_unverified_token() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = host_with_project(state, state_builder, false);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&retract_params(token_id));
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(1000));
+
+        let result = retract(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(90.into()));
+        let event = || BurnEvent {
+            token_id,
+            owner: OWNER,
+            amount: 10.into(),
+        };
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::Retract(event())),
+                to_bytes(&ContractEvent::Burn(event())),
+            ]
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_retract_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder, 100);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::default();
+        let result = retract(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(result, Err(CustomContractError::Paused.into()));
+    }
+}
@@ -3,9 +3,13 @@ use core::ops::{AddAssign, SubAssign};
 use concordium_cis2::*;
 use concordium_std::*;
 
-use crate::client_utils::types::ContractMetadataUrl;
+use crate::client_utils::{
+    roles::Roles,
+    types::{CertificateId, ContractMetadataUrl, RetirementDetails, MATURITY_BASIS_POINTS},
+    upgrade::StateVersion,
+};
 
-use super::{error::*, contract_types::{ContractTokenId, ContractTokenAmount, ContractCollateralTokenAmount, ContractResult}};
+use super::{error::*, contract_types::{ContractTokenId, ContractTokenAmount, ContractCollateralTokenAmount, ContractResult, ContractRetirementCertificate}};
 
 /// The state for each address.
 #[derive(Serial, DeserialWithState, Deletable, StateClone)]
@@ -32,6 +36,10 @@ pub struct CollateralToken {
     pub owner: AccountAddress,
 }
 
+/// Version of the layout of [`State`]. See [`crate::client_utils::upgrade`]
+/// for when it is increased.
+pub const STATE_VERSION: StateVersion = 1;
+
 /// The contract state,
 ///
 /// Note: The specification does not specify how to structure the contract state
@@ -39,28 +47,51 @@ pub struct CollateralToken {
 #[derive(Serial, DeserialWithState, StateClone)]
 #[concordium(state_parameter = "S")]
 pub struct State<S> {
+    /// Version of the layout of the state. Always the first field.
+    pub version: StateVersion,
     /// The state of addresses.
     pub state: StateMap<Address, AddressState<S>, S>,
     /// All of the token IDs
     pub tokens: StateMap<ContractTokenId, MetadataUrl, S>,
     pub token_supply: StateMap<ContractTokenId, ContractTokenAmount, S>,
+    /// Amount of each token which was minted, regardless of burns.
+    pub minted_supply: StateMap<ContractTokenId, ContractTokenAmount, S>,
+    /// Amount of each token which was retired.
+    pub retired_supply: StateMap<ContractTokenId, ContractTokenAmount, S>,
     pub collaterals: StateMap<CollateralToken, ContractCollateralTokenAmount, S>,
     pub used_collaterals: StateMap<ContractTokenId, CollateralToken, S>,
     pub last_token_id: ContractTokenId,
     // Contracts from which incoming CIS2 transfers will be accepted
     pub verifier_contracts: StateSet<ContractAddress, S>,
+    /// Operators of each owner address.
+    pub operators: StateMap<Address, StateSet<Address, S>, S>,
+    /// Retirement certificates by their ID.
+    pub retirements: StateMap<CertificateId, ContractRetirementCertificate, S>,
+    /// ID of the next retirement certificate.
+    pub next_certificate_id: CertificateId,
+    /// Roles of the addresses.
+    pub roles: Roles<S>,
+    /// Whether the transfers, mints, retirements and retractions are paused.
+    pub paused: bool,
+    /// Whether the metadata url of a token must have a hash.
+    pub metadata_hash_required: bool,
 }
 
 impl<S: HasStateApi> State<S> {
     /// Construct a state with no tokens
     pub fn new(
         state_builder: &mut StateBuilder<S>,
+        admin: Address,
         verifier_contracts: Vec<ContractAddress>,
+        metadata_hash_required: bool,
     ) -> Self {
         State {
+            version: STATE_VERSION,
             state: state_builder.new_map(),
             tokens: state_builder.new_map(),
             token_supply: state_builder.new_map(),
+            minted_supply: state_builder.new_map(),
+            retired_supply: state_builder.new_map(),
             last_token_id: 0.into(),
             collaterals: state_builder.new_map(),
             used_collaterals: state_builder.new_map(),
@@ -71,6 +102,12 @@ impl<S: HasStateApi> State<S> {
                 }
                 set
             },
+            operators: state_builder.new_map(),
+            retirements: state_builder.new_map(),
+            next_certificate_id: 0,
+            roles: Roles::new(admin, state_builder),
+            paused: false,
+            metadata_hash_required,
         }
     }
 
@@ -79,16 +116,45 @@ impl<S: HasStateApi> State<S> {
         self.verifier_contracts.contains(contract)
     }
 
+    /// Adds a verifier contract.
+    /// Returns false if the contract is already a verifier contract.
+    pub fn add_verifier_contract(&mut self, contract: ContractAddress) -> bool {
+        self.verifier_contracts.insert(contract)
+    }
+
+    /// Removes a verifier contract.
+    /// Returns false if the contract is not a verifier contract.
+    pub fn remove_verifier_contract(&mut self, contract: &ContractAddress) -> bool {
+        self.verifier_contracts.remove(contract)
+    }
+
+    /// Converts the metadata url of a token. Rejects a malformed hash, or a
+    /// missing hash if hashes are required.
+    pub fn metadata_url(&self, metadata_url: ContractMetadataUrl) -> ContractResult<MetadataUrl> {
+        let metadata_url: MetadataUrl = metadata_url.try_into()?;
+        ensure!(
+            !self.metadata_hash_required || metadata_url.hash.is_some(),
+            CustomContractError::MissingMetadataHash.into()
+        );
+
+        Ok(metadata_url)
+    }
+
     /// Mints an amount of tokens with a given address as the owner.
     pub fn mint(
         &mut self,
-        token_metadata: &ContractMetadataUrl,
+        token_metadata: MetadataUrl,
         amount: ContractTokenAmount,
         owner: &Address,
         state_builder: &mut StateBuilder<S>,
-    ) -> ContractTokenId {
+    ) -> ContractResult<ContractTokenId> {
         let token_id = self.last_token_id;
-        self.tokens.insert(token_id, token_metadata.clone().into());
+        // Allocate the token ID for the next mint.
+        let next_token_id = token_id
+            .0
+            .checked_add(1)
+            .ok_or(CustomContractError::TokenIdsExhausted)?;
+        self.tokens.insert(token_id, token_metadata);
 
         self.state
             .entry(*owner)
@@ -108,10 +174,14 @@ impl<S: HasStateApi> State<S> {
             .entry(token_id)
             .and_modify(|a| a.add_assign(amount))
             .or_insert(amount);
+        self.minted_supply
+            .entry(token_id)
+            .and_modify(|a| a.add_assign(amount))
+            .or_insert(amount);
 
         // Update the last token id
-        self.last_token_id = ContractTokenId::from(token_id.0 + 1);
-        token_id
+        self.last_token_id = ContractTokenId::from(next_token_id);
+        Ok(token_id)
     }
 
     pub fn burn(
@@ -131,6 +201,58 @@ impl<S: HasStateApi> State<S> {
             .and_modify(|a| a.sub_assign(amount));
     }
 
+    /// Amount of a token which can still be retired, when the given fraction of
+    /// its minted amount has matured. The fraction is given in basis points.
+    pub fn retirable_amount(
+        &self,
+        token_id: &ContractTokenId,
+        matured_fraction: u16,
+    ) -> ContractTokenAmount {
+        let minted = self.minted_supply.get(token_id).map_or(0, |a| a.0);
+        let retired = self.retired_supply.get(token_id).map_or(0, |a| a.0);
+        let matured =
+            u128::from(minted) * u128::from(matured_fraction) / u128::from(MATURITY_BASIS_POINTS);
+
+        // The matured amount is at most the minted amount, which is a u64.
+        (matured as u64).saturating_sub(retired).into()
+    }
+
+    /// Records the retirement of a token and returns its certificate.
+    pub fn add_retirement(
+        &mut self,
+        token_id: ContractTokenId,
+        amount: ContractTokenAmount,
+        owner: Address,
+        retired_at: Timestamp,
+        details: RetirementDetails,
+    ) -> ContractRetirementCertificate {
+        let certificate = ContractRetirementCertificate {
+            certificate_id: self.next_certificate_id,
+            token_id,
+            amount,
+            owner,
+            retired_at,
+            details,
+        };
+        self.retirements
+            .insert(certificate.certificate_id, certificate.clone());
+        self.next_certificate_id += 1;
+        self.retired_supply
+            .entry(token_id)
+            .and_modify(|a| a.add_assign(amount))
+            .or_insert(amount);
+
+        certificate
+    }
+
+    /// Gets the certificate of a retirement.
+    pub fn get_retirement(
+        &self,
+        certificate_id: &CertificateId,
+    ) -> Option<ContractRetirementCertificate> {
+        self.retirements.get(certificate_id).map(|c| c.to_owned())
+    }
+
     /// Check that the token ID currently exists in this contract.
     #[inline(always)]
     pub fn contains_token(&self, token_id: &ContractTokenId) -> bool {
@@ -207,6 +329,38 @@ impl<S: HasStateApi> State<S> {
         Ok(())
     }
 
+    /// Check if an address is an operator of a given owner address.
+    pub fn is_operator(&self, address: &Address, owner: &Address) -> bool {
+        self.operators
+            .get(owner)
+            .map(|operators| operators.contains(address))
+            .unwrap_or(false)
+    }
+
+    /// Update the state adding a new operator for a given address.
+    /// Succeeds even if the `operator` is already an operator for the
+    /// `address`.
+    pub fn add_operator(
+        &mut self,
+        owner: &Address,
+        operator: &Address,
+        state_builder: &mut StateBuilder<S>,
+    ) {
+        let mut owner_operators = self
+            .operators
+            .entry(*owner)
+            .or_insert_with(|| state_builder.new_set());
+        owner_operators.insert(*operator);
+    }
+
+    /// Update the state removing an operator for a given address.
+    /// Succeeds even if the `operator` is not an operator for the `address`.
+    pub fn remove_operator(&mut self, owner: &Address, operator: &Address) {
+        self.operators.entry(*owner).and_modify(|operators| {
+            operators.remove(operator);
+        });
+    }
+
     pub fn add_collateral(
         &mut self,
         contract: ContractAddress,
@@ -276,3 +430,115 @@ impl<S: HasStateApi> State<S> {
         )
     }
 }
+
+/// Helpers to set up the state in the tests of the entrypoints.
+#[concordium_cfg_test]
+pub(crate) mod test_utils {
+    use super::*;
+    use crate::client_utils::{
+        client::{IS_VERIFIED_ENTRYPOINT_NAME, MATURITY_OF_ENTRYPOINT_NAME},
+        types::{IsVerifiedQueryParams, MaturitySchedule, MaturityTranche},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    pub const ADMIN: Address = Address::Account(AccountAddress([0u8; 32]));
+    pub const OWNER_ACCOUNT: AccountAddress = AccountAddress([1u8; 32]);
+    pub const OWNER: Address = Address::Account(OWNER_ACCOUNT);
+    /// The project token contract holding the collaterals.
+    pub const PROJECT_CONTRACT: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+    pub const PROJECT_TOKEN_ID: ContractTokenId = TokenIdU32(7);
+
+    /// A state administered by `ADMIN` with an amount of a token minted to
+    /// `OWNER`, against the collateral of `PROJECT_TOKEN_ID`.
+    pub fn state_with_token(
+        state_builder: &mut TestStateBuilder,
+        amount: u64,
+    ) -> (State<TestStateApi>, ContractTokenId) {
+        let mut state = State::new(state_builder, ADMIN, vec![PROJECT_CONTRACT], false);
+        let collateral_key = CollateralToken {
+            contract: PROJECT_CONTRACT,
+            token_id: PROJECT_TOKEN_ID,
+            owner: OWNER_ACCOUNT,
+        };
+        state.add_collateral(PROJECT_CONTRACT, PROJECT_TOKEN_ID, OWNER_ACCOUNT, 1.into());
+        let metadata_url = MetadataUrl {
+            url: "https://example.com/credit/1.json".to_string(),
+            hash: None,
+        };
+        let token_id = state
+            .mint(metadata_url, amount.into(), &OWNER, state_builder)
+            .expect_report("Token is minted");
+        state
+            .use_collateral(&collateral_key, &token_id)
+            .expect_report("Collateral is used");
+
+        (state, token_id)
+    }
+
+    /// A schedule by which the fraction of the credits, in basis points,
+    /// matures at each time.
+    pub fn maturity(tranches: &[(u64, u16)]) -> MaturitySchedule {
+        MaturitySchedule {
+            tranches: tranches
+                .iter()
+                .map(|(time, fraction)| MaturityTranche {
+                    time: Timestamp::from_timestamp_millis(*time),
+                    fraction: *fraction,
+                })
+                .collect(),
+        }
+    }
+
+    /// Mocks the `maturityOf` and `isVerified` entrypoints of the project
+    /// token contract. Only `PROJECT_TOKEN_ID` can be verified.
+    pub fn setup_project_mocks(
+        host: &mut TestHost<State<TestStateApi>>,
+        maturity: MaturitySchedule,
+        is_verified: bool,
+    ) {
+        host.setup_mock_entrypoint(
+            PROJECT_CONTRACT,
+            OwnedEntrypointName::new_unchecked(MATURITY_OF_ENTRYPOINT_NAME.to_string()),
+            MockFn::returning_ok(vec![maturity]),
+        );
+        host.setup_mock_entrypoint(
+            PROJECT_CONTRACT,
+            OwnedEntrypointName::new_unchecked(IS_VERIFIED_ENTRYPOINT_NAME.to_string()),
+            MockFn::new_v1(move |parameter, _, _, _| {
+                let params: IsVerifiedQueryParams<ContractTokenId> =
+                    from_bytes(parameter.as_ref()).expect_report("Valid parameter");
+                let response: Vec<bool> = params
+                    .queries
+                    .iter()
+                    .map(|token_id| is_verified && *token_id == PROJECT_TOKEN_ID)
+                    .collect();
+                Ok((false, response))
+            }),
+        );
+    }
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::{test_utils::*, *};
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_not_mint_when_token_ids_are_exhausted() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder, 100);
+        state.last_token_id = TokenIdU32(u32::MAX);
+
+        let metadata_url = MetadataUrl {
+            url: "https://example.com/credit/2.json".to_string(),
+            hash: None,
+        };
+        let result = state.mint(metadata_url, 100.into(), &OWNER, &mut state_builder);
+        assert_eq!(result, Err(CustomContractError::TokenIdsExhausted.into()));
+        assert!(!state.contains_token(&TokenIdU32(u32::MAX)));
+        assert_eq!(state.last_token_id, TokenIdU32(u32::MAX));
+    }
+}
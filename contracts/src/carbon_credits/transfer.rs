@@ -32,6 +32,8 @@ fn contract_transfer<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(!host.state().paused, CustomContractError::Paused.into());
     // Parse the parameter.
     let TransferParams(transfers): TransferParameter = ctx.parameter_cursor().get()?;
     // Get the sender who invoked this contract function.
@@ -47,7 +49,10 @@ fn contract_transfer<S: HasStateApi>(
     {
         let (state, builder) = host.state_and_builder();
         // Authenticate the sender for this transfer
-        ensure!(from == sender, ContractError::Unauthorized);
+        ensure!(
+            from == sender || state.is_operator(&sender, &from),
+            ContractError::Unauthorized
+        );
 
         let to_address = to.address();
         // Tokens are being transferred to another address
@@ -81,3 +86,83 @@ fn contract_transfer<S: HasStateApi>(
 
     Ok(())
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::carbon_credits::{state::test_utils::*, update_operator::update_operator};
+    use concordium_cis2::*;
+    use concordium_std::test_infrastructure::*;
+
+    const OPERATOR: Address = Address::Account(AccountAddress([2u8; 32]));
+    const RECEIVER: AccountAddress = AccountAddress([3u8; 32]);
+
+    #[concordium_test]
+    fn should_transfer_by_operator() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter: TransferParameter = TransferParams(vec![Transfer {
+            token_id,
+            amount: 40.into(),
+            from: OWNER,
+            to: Receiver::Account(RECEIVER),
+            data: AdditionalData::empty(),
+        }]);
+        let parameter_bytes = to_bytes(&parameter);
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OPERATOR);
+
+        let update = |update| {
+            to_bytes(&UpdateOperatorParams(vec![UpdateOperator {
+                update,
+                operator: OPERATOR,
+            }]))
+        };
+        let mut update_ctx = TestReceiveContext::default();
+        update_ctx.set_sender(OWNER);
+
+        let update_bytes = update(OperatorUpdate::Add);
+        update_ctx.set_parameter(&update_bytes);
+        let result = update_operator(&update_ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        let result = contract_transfer(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(60.into()));
+        assert_eq!(
+            host.state().balance(&token_id, &Address::Account(RECEIVER)),
+            Ok(40.into())
+        );
+
+        // Removed operators can no longer transfer.
+        let update_bytes = update(OperatorUpdate::Remove);
+        update_ctx.set_parameter(&update_bytes);
+        let result = update_operator(&update_ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        let result = contract_transfer(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(
+            logger.logs[2],
+            to_bytes(&ContractEvent::UpdateOperator(UpdateOperatorEvent {
+                owner: OWNER,
+                operator: OPERATOR,
+                update: OperatorUpdate::Remove,
+            }))
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_transfer_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder, 100);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::default();
+        let result = contract_transfer(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(result, Err(CustomContractError::Paused.into()));
+    }
+}
@@ -1,4 +1,5 @@
-use super::{contract_types::*, error::*, state::*};
+use super::{contract_types::*, events::*, state::*};
+use concordium_cis2::*;
 use concordium_std::*;
 
 /// Enable or disable addresses as operators of the sender address.
@@ -10,15 +11,35 @@ use concordium_std::*;
 #[receive(
     contract = "carbon_credits",
     name = "updateOperator",
-    parameter = "concordium_cis2::UpdateOperatorParams",
-    error = "ContractError",
+    parameter = "UpdateOperatorParams",
+    error = "super::error::ContractError",
     enable_logger,
     mutable
 )]
 pub fn update_operator<S: HasStateApi>(
-    _ctx: &impl HasReceiveContext,
-    _host: &mut impl HasHost<State<S>, StateApiType = S>,
-    _logger: &mut impl HasLogger,
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
-    Err(ContractError::Custom(CustomContractError::NotImplemented))
+    // Parse the parameter.
+    let UpdateOperatorParams(params) = ctx.parameter_cursor().get()?;
+    // Get the sender who invoked this contract function.
+    let sender = ctx.sender();
+    let (state, builder) = host.state_and_builder();
+    for param in params {
+        // Update the operator in the state.
+        match param.update {
+            OperatorUpdate::Add => state.add_operator(&sender, &param.operator, builder),
+            OperatorUpdate::Remove => state.remove_operator(&sender, &param.operator),
+        }
+
+        // Log the appropriate event
+        logger.log(&ContractEvent::UpdateOperator(UpdateOperatorEvent {
+            owner: sender,
+            operator: param.operator,
+            update: param.update,
+        }))?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,129 @@
+use concordium_std::*;
+
+use crate::client_utils::upgrade::{self, UpgradeParams};
+
+use super::{contract_types::ContractResult, error::*, state::State};
+
+/// Upgrades the contract to a new module and calls the migration entrypoint
+/// of the new module, if any. Can only be called by the admin.
+#[receive(
+    contract = "carbon_credits",
+    name = "upgrade",
+    parameter = "UpgradeParams",
+    error = "ContractError",
+    low_level
+)]
+pub fn contract_upgrade<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<S, StateApiType = S>,
+) -> ContractResult<()> {
+    // Read the state with the layout of this module to authenticate the sender.
+    let state: State<S> = host.state().read_root()?;
+    ensure!(
+        ctx.sender() == state.roles.admin(),
+        ContractError::Unauthorized
+    );
+    // Parse the parameter.
+    let params: UpgradeParams = ctx.parameter_cursor().get()?;
+
+    upgrade::upgrade(&ctx.self_address(), host, params)?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        carbon_credits::state::{test_utils::*, STATE_VERSION},
+        client_utils::upgrade::{test_utils::LowLevelTestHost, ContractUpgradeError},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    const SELF_ADDRESS: ContractAddress = ContractAddress {
+        index: 2,
+        subindex: 0,
+    };
+
+    fn module() -> ModuleReference {
+        ModuleReference::new([2u8; 32])
+    }
+
+    #[concordium_test]
+    fn should_upgrade_and_migrate() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_upgrade(module(), Ok(()));
+        // The migration reads the version the state has been stored with.
+        host.setup_mock_entrypoint(
+            SELF_ADDRESS,
+            OwnedEntrypointName::new_unchecked("migrate".to_string()),
+            MockFn::new_v1(|_, _, _, state: &mut State<TestStateApi>| {
+                assert_eq!(state.version, STATE_VERSION);
+                state.version += 1;
+                Ok((true, ()))
+            }),
+        );
+
+        let result = upgrade::upgrade(
+            &SELF_ADDRESS,
+            &mut host,
+            UpgradeParams {
+                module: module(),
+                migrate: Some((
+                    OwnedEntrypointName::new_unchecked("migrate".to_string()),
+                    OwnedParameter::empty(),
+                )),
+            },
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(host.state().version, STATE_VERSION + 1);
+        // The balances are kept.
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(100.into()));
+    }
+
+    #[concordium_test]
+    fn should_not_upgrade_to_missing_module() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, _) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_upgrade(module(), Err(UpgradeError::MissingModule));
+
+        let result = upgrade::upgrade(
+            &SELF_ADDRESS,
+            &mut host,
+            UpgradeParams {
+                module: module(),
+                migrate: None,
+            },
+        );
+
+        assert_eq!(result, Err(ContractUpgradeError::FailedUpgrade));
+    }
+
+    #[concordium_test]
+    fn should_upgrade_by_admin_only() {
+        let mut host = LowLevelTestHost::new();
+        let (state, _) = state_with_token(host.state_builder(), 100);
+        host.state_mut().write_root(&state);
+        host.host.setup_mock_upgrade(module(), Ok(()));
+
+        let parameter_bytes = to_bytes(&UpgradeParams {
+            module: module(),
+            migrate: None,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_self_address(SELF_ADDRESS);
+
+        ctx.set_sender(OWNER);
+        let result = contract_upgrade(&ctx, &mut host);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        ctx.set_sender(ADMIN);
+        let result = contract_upgrade(&ctx, &mut host);
+        assert_eq!(result, Ok(()));
+    }
+}
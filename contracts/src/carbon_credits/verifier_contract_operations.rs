@@ -0,0 +1,139 @@
+use concordium_std::*;
+
+use crate::client_utils::{
+    roles::Role,
+    types::{VerifierContractParams, VerifierContractUpdatedEvent},
+};
+
+use super::{contract_types::ContractResult, error::*, events::*, state::State};
+
+/// Adds a contract from which collateral and verifications are accepted. Can
+/// only be called by an admin.
+#[receive(
+    contract = "carbon_credits",
+    name = "addVerifierContract",
+    parameter = "VerifierContractParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn add_verifier_contract<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Parse the parameter.
+    let VerifierContractParams { contract } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Authenticate the sender
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        ContractError::Unauthorized
+    );
+    // Update the contract state
+    state.add_verifier_contract(contract);
+    logger.log(&ContractEvent::VerifierContractAdded(
+        VerifierContractUpdatedEvent { contract },
+    ))?;
+
+    Ok(())
+}
+
+/// Removes a verifier contract. Can only be called by an admin.
+#[receive(
+    contract = "carbon_credits",
+    name = "removeVerifierContract",
+    parameter = "VerifierContractParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn remove_verifier_contract<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Parse the parameter.
+    let VerifierContractParams { contract } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Authenticate the sender
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        ContractError::Unauthorized
+    );
+    // Update the contract state
+    state.remove_verifier_contract(&contract);
+    logger.log(&ContractEvent::VerifierContractRemoved(
+        VerifierContractUpdatedEvent { contract },
+    ))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::carbon_credits::{state::test_utils::*, verifier_contracts::verifier_contracts};
+    use concordium_std::test_infrastructure::*;
+
+    const VERIFIER_CONTRACT: ContractAddress = ContractAddress {
+        index: 2,
+        subindex: 0,
+    };
+
+    #[concordium_test]
+    fn should_add_and_remove_verifier_contracts() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, _) = state_with_token(&mut state_builder, 100);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let add_bytes = to_bytes(&VerifierContractParams {
+            contract: VERIFIER_CONTRACT,
+        });
+        let remove_bytes = to_bytes(&VerifierContractParams {
+            contract: PROJECT_CONTRACT,
+        });
+        let mut add_ctx = TestReceiveContext::default();
+        add_ctx.set_parameter(&add_bytes);
+        let mut remove_ctx = TestReceiveContext::default();
+        remove_ctx.set_parameter(&remove_bytes);
+
+        // Only an admin can add or remove verifier contracts.
+        add_ctx.set_sender(OWNER);
+        let result = add_verifier_contract(&add_ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        remove_ctx.set_sender(OWNER);
+        let result = remove_verifier_contract(&remove_ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        add_ctx.set_sender(ADMIN);
+        let result = add_verifier_contract(&add_ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        let contracts = verifier_contracts(&TestReceiveContext::default(), &host);
+        assert_eq!(contracts, Ok(vec![PROJECT_CONTRACT, VERIFIER_CONTRACT]));
+
+        remove_ctx.set_sender(ADMIN);
+        let result = remove_verifier_contract(&remove_ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        let contracts = verifier_contracts(&TestReceiveContext::default(), &host);
+        assert_eq!(contracts, Ok(vec![VERIFIER_CONTRACT]));
+        assert!(!host.state().is_verifier_contract(&PROJECT_CONTRACT));
+
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::VerifierContractAdded(
+                    VerifierContractUpdatedEvent {
+                        contract: VERIFIER_CONTRACT,
+                    }
+                )),
+                to_bytes(&ContractEvent::VerifierContractRemoved(
+                    VerifierContractUpdatedEvent {
+                        contract: PROJECT_CONTRACT,
+                    }
+                )),
+            ]
+        );
+    }
+}
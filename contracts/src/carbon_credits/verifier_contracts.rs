@@ -0,0 +1,24 @@
+use concordium_std::*;
+
+use crate::client_utils::types::VerifierContractsQueryResponse;
+
+use super::{contract_types::ContractResult, state::State};
+
+/// Returns the contracts from which collateral and verifications are accepted.
+#[receive(
+    contract = "carbon_credits",
+    name = "verifierContracts",
+    return_value = "VerifierContractsQueryResponse",
+    error = "super::error::ContractError"
+)]
+pub fn verifier_contracts<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<VerifierContractsQueryResponse> {
+    Ok(host
+        .state()
+        .verifier_contracts
+        .iter()
+        .map(|contract| *contract)
+        .collect())
+}
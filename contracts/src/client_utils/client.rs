@@ -16,7 +16,8 @@ use super::{
     errors::Cis2ClientError,
     types::{
         IsVerifiedQueryParams, IsVerifiedQueryResponse, IsVerifierQueryParams,
-        MaturityOfQueryParams, MaturityOfQueryResponse, IsVerifierQueryResponse,
+        IsVerifierQueryResponse, MaturityOfQueryParams, MaturityOfQueryResponse, MaturitySchedule,
+        ProjectInfo, ProjectInfoQueryParams, ProjectInfoQueryResponse,
     },
 };
 
@@ -28,6 +29,8 @@ pub const TRANSFER_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_uncheck
 pub const MATURITY_OF_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_unchecked("maturityOf");
 pub const IS_VERIFIED_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_unchecked("isVerified");
 pub const IS_VERIFIER_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_unchecked("isVerifier");
+pub const PROJECT_INFO_ENTRYPOINT_NAME: EntrypointName =
+    EntrypointName::new_unchecked("projectInfo");
 
 pub struct Client;
 
@@ -176,7 +179,7 @@ impl Client {
         host: &impl HasHost<State, StateApiType = S>,
         token_id: T,
         contract_address: ContractAddress,
-    ) -> Result<Timestamp, Cis2ClientError> {
+    ) -> Result<MaturitySchedule, Cis2ClientError> {
         let params = MaturityOfQueryParams {
             queries: vec![token_id],
         };
@@ -193,11 +196,41 @@ impl Client {
             _ => bail!(Cis2ClientError::InvokeContractError),
         };
 
-        let maturity_time = *parsed_res
-            .first()
+        let maturity = parsed_res
+            .into_iter()
+            .next()
+            .ok_or(Cis2ClientError::InvokeContractError)?;
+
+        Ok(maturity)
+    }
+
+    pub fn project_info<State, S: HasStateApi, T: IsTokenId>(
+        host: &impl HasHost<State, StateApiType = S>,
+        token_id: T,
+        contract_address: ContractAddress,
+    ) -> Result<ProjectInfo, Cis2ClientError> {
+        let params = ProjectInfoQueryParams {
+            queries: vec![token_id],
+        };
+
+        let res = host.invoke_contract_read_only(
+            &contract_address,
+            &params,
+            PROJECT_INFO_ENTRYPOINT_NAME,
+            Amount::from_ccd(0),
+        );
+
+        let parsed_res = match res {
+            Ok(Some(mut res)) => ProjectInfoQueryResponse::deserial(&mut res).unwrap(),
+            _ => bail!(Cis2ClientError::InvokeContractError),
+        };
+
+        let project_info = parsed_res
+            .into_iter()
+            .next()
             .ok_or(Cis2ClientError::InvokeContractError)?;
 
-        Ok(maturity_time)
+        Ok(project_info)
     }
 
     pub fn is_verified<State, S: HasStateApi, T: IsTokenId>(
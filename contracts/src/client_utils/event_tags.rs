@@ -0,0 +1,95 @@
+//! Registry of the tags of the custom events logged by the contracts.
+//!
+//! CIS-2 reserves the tags from 255 downwards for its own events, custom
+//! events use the tags from 0 upwards. A tag has the same meaning in
+//! `project_token` and `carbon_credits` and is never reused for another event.
+//!
+//! The events of `carbon_credit_market` are not part of the registry. Their
+//! tags are the indices of the variants of its `ContractEvent` and overlap
+//! with the tags here.
+//!
+//! Changes between versions:
+//! - Version 1: The collateral events of `carbon_credits` moved from tags 1, 2
+//!   and 3 to tags 6, 7 and 8, as tags 1, 2 and 3 are used by `project_token`.
+
+/// Version of the registry. Increased whenever the tag of an event changes.
+pub const EVENT_TAGS_VERSION: u8 = 1;
+
+pub const RETIRE_EVENT_TAG: u8 = 0;
+pub const MATURITY_TIME_EVENT_TAG: u8 = 1;
+pub const VERIFIER_ADDED_EVENT_TAG: u8 = 2;
+pub const VERIFIER_REMOVED_EVENT_TAG: u8 = 3;
+pub const VERIFICATION_EVENT_TAG: u8 = 4;
+pub const RETRACT_EVENT_TAG: u8 = 5;
+pub const COLLATERAL_ADDED_EVENT_TAG: u8 = 6;
+pub const COLLATERAL_REMOVED_EVENT_TAG: u8 = 7;
+pub const COLLATERAL_USED_EVENT_TAG: u8 = 8;
+pub const RETIREMENT_CERTIFICATE_EVENT_TAG: u8 = 9;
+pub const QUORUM_UPDATED_EVENT_TAG: u8 = 10;
+pub const VERIFICATION_REVOKED_EVENT_TAG: u8 = 11;
+pub const VERIFICATIONS_AFFECTED_EVENT_TAG: u8 = 12;
+pub const ROLE_GRANTED_EVENT_TAG: u8 = 13;
+pub const ROLE_REVOKED_EVENT_TAG: u8 = 14;
+pub const ADMIN_PROPOSED_EVENT_TAG: u8 = 15;
+pub const ADMIN_TRANSFERRED_EVENT_TAG: u8 = 16;
+pub const PAUSED_EVENT_TAG: u8 = 17;
+pub const UNPAUSED_EVENT_TAG: u8 = 18;
+pub const VERIFIER_CONTRACT_ADDED_EVENT_TAG: u8 = 19;
+pub const VERIFIER_CONTRACT_REMOVED_EVENT_TAG: u8 = 20;
+pub const METADATA_FROZEN_EVENT_TAG: u8 = 21;
+pub const MATURITY_AMENDED_EVENT_TAG: u8 = 22;
+
+#[concordium_std::concordium_cfg_test]
+mod test {
+    use crate::{carbon_credits, project_token};
+    use concordium_cis2::{
+        BURN_EVENT_TAG, MINT_EVENT_TAG, TOKEN_METADATA_EVENT_TAG, TRANSFER_EVENT_TAG,
+        UPDATE_OPERATOR_EVENT_TAG,
+    };
+    use concordium_std::{
+        collections::BTreeMap,
+        schema::{SchemaType, Type},
+        *,
+    };
+
+    /// Names of the events by their tag, from the schema of the events.
+    fn event_names(schema: Type) -> BTreeMap<u8, String> {
+        match schema {
+            Type::TaggedEnum(variants) => variants
+                .into_iter()
+                .map(|(tag, (name, _))| (tag, name))
+                .collect(),
+            _ => fail!("Events should have a tagged enum schema"),
+        }
+    }
+
+    #[concordium_test]
+    fn should_use_tags_for_the_same_events_in_both_contracts() {
+        let project_token_events = event_names(project_token::events::ContractEvent::get_type());
+        let carbon_credits_events = event_names(carbon_credits::events::ContractEvent::get_type());
+        let cis2_tags = [
+            TRANSFER_EVENT_TAG,
+            MINT_EVENT_TAG,
+            BURN_EVENT_TAG,
+            UPDATE_OPERATOR_EVENT_TAG,
+            TOKEN_METADATA_EVENT_TAG,
+        ];
+
+        for (tag, name) in carbon_credits_events.iter() {
+            if let Some(project_token_name) = project_token_events.get(tag) {
+                assert_eq!(name, project_token_name, "Events share the tag {}", tag);
+            }
+        }
+        // Custom events do not use the tags reserved by CIS-2.
+        for tag in project_token_events
+            .keys()
+            .chain(carbon_credits_events.keys())
+        {
+            assert!(
+                *tag < TOKEN_METADATA_EVENT_TAG || cis2_tags.contains(tag),
+                "Tag {} is reserved by CIS-2",
+                tag
+            );
+        }
+    }
+}
@@ -1,3 +1,6 @@
 pub mod types;
 pub mod client;
 pub mod errors;
+pub mod event_tags;
+pub mod roles;
+pub mod upgrade;
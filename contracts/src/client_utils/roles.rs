@@ -0,0 +1,195 @@
+//! Role based access control shared by the contracts.
+//!
+//! Every contract stores the roles of its addresses in [`Roles`] and exposes
+//! the `grantRole` and `revokeRole` entrypoints, which can only be called by an
+//! [`Role::Admin`].
+//!
+//! The admin of a contract is stored with the roles and always has the
+//! [`Role::Admin`] role. Further admins can be granted the role, which forms
+//! an admin set. The admin is handed over in two steps: the admin proposes a
+//! new admin with `proposeAdmin` and the proposed admin accepts with
+//! `acceptAdmin`, so that the admin can not be handed to an address which is
+//! unable to use it.
+
+use concordium_std::*;
+
+/// A role which allows an address to call the privileged functions of a
+/// contract.
+#[derive(Serial, Deserial, SchemaType, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    /// Grants and revokes roles and manages the contract.
+    Admin,
+    /// Verifies projects.
+    Verifier,
+    /// Mints projects.
+    Minter,
+    /// Pauses the contract.
+    Pauser,
+    /// Manages the fees charged by the contract.
+    FeeManager,
+}
+
+/// Parameter of the `grantRole` and `revokeRole` entrypoints.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct RoleParams {
+    pub address: Address,
+    pub role: Role,
+}
+
+/// Event logged when a role is granted to or revoked from an address.
+#[derive(Serial, Deserial, SchemaType, Debug, PartialEq, Eq)]
+pub struct RoleUpdatedEvent {
+    pub address: Address,
+    pub role: Role,
+}
+
+/// Parameter of the `proposeAdmin` entrypoint.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct ProposeAdminParams {
+    pub admin: Address,
+}
+
+/// Event logged when the admin proposes a new admin.
+#[derive(Serial, Deserial, SchemaType, Debug, PartialEq, Eq)]
+pub struct AdminProposedEvent {
+    pub admin: Address,
+    pub proposed_admin: Address,
+}
+
+/// Event logged when the proposed admin accepts the admin of the contract.
+#[derive(Serial, Deserial, SchemaType, Debug, PartialEq, Eq)]
+pub struct AdminTransferredEvent {
+    pub previous_admin: Address,
+    pub admin: Address,
+}
+
+/// Roles of the addresses of a contract.
+#[derive(Serial, DeserialWithState, StateClone)]
+#[concordium(state_parameter = "S")]
+pub struct Roles<S> {
+    /// The admin of the contract.
+    admin: Address,
+    /// The admin proposed by the admin, which has not accepted yet.
+    proposed_admin: Option<Address>,
+    roles: StateMap<Address, StateSet<Role, S>, S>,
+}
+
+impl<S: HasStateApi> Roles<S> {
+    /// Creates roles with only the admin.
+    pub fn new(admin: Address, state_builder: &mut StateBuilder<S>) -> Self {
+        Roles {
+            admin,
+            proposed_admin: None,
+            roles: state_builder.new_map(),
+        }
+    }
+
+    /// The admin of the contract.
+    pub fn admin(&self) -> Address {
+        self.admin
+    }
+
+    /// The admin proposed by the admin, if any.
+    pub fn proposed_admin(&self) -> Option<Address> {
+        self.proposed_admin
+    }
+
+    /// Proposes a new admin, replacing any earlier proposal.
+    pub fn propose_admin(&mut self, admin: Address) {
+        self.proposed_admin = Some(admin);
+    }
+
+    /// Makes the proposed admin the admin of the contract.
+    /// Returns the previous admin or `None` if the address is not the proposed
+    /// admin.
+    pub fn accept_admin(&mut self, address: &Address) -> Option<Address> {
+        if self.proposed_admin != Some(*address) {
+            return None;
+        }
+
+        self.proposed_admin = None;
+        Some(core::mem::replace(&mut self.admin, *address))
+    }
+
+    /// Checks if the address has the role.
+    pub fn has_role(&self, address: &Address, role: Role) -> bool {
+        if role == Role::Admin && *address == self.admin {
+            return true;
+        }
+
+        self.roles
+            .get(address)
+            .map(|roles| roles.contains(&role))
+            .unwrap_or(false)
+    }
+
+    /// Grants the role to the address.
+    /// Returns false if the address already has the role.
+    pub fn grant(
+        &mut self,
+        address: &Address,
+        role: Role,
+        state_builder: &mut StateBuilder<S>,
+    ) -> bool {
+        self.roles
+            .entry(*address)
+            .or_insert_with(|| state_builder.new_set())
+            .insert(role)
+    }
+
+    /// Revokes the role from the address.
+    /// Returns false if the address does not have the role.
+    pub fn revoke(&mut self, address: &Address, role: Role) -> bool {
+        self.roles
+            .get_mut(address)
+            .map(|mut roles| roles.remove(&role))
+            .unwrap_or(false)
+    }
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use concordium_std::test_infrastructure::*;
+
+    const ADMIN: Address = Address::Account(AccountAddress([0u8; 32]));
+    const ACCOUNT: Address = Address::Account(AccountAddress([1u8; 32]));
+
+    #[concordium_test]
+    fn should_grant_and_revoke_roles() {
+        let mut state_builder = TestStateBuilder::new();
+        let mut roles = Roles::new(ADMIN, &mut state_builder);
+
+        assert!(roles.has_role(&ADMIN, Role::Admin));
+        assert!(!roles.has_role(&ACCOUNT, Role::Minter));
+
+        assert!(roles.grant(&ACCOUNT, Role::Minter, &mut state_builder));
+        assert!(!roles.grant(&ACCOUNT, Role::Minter, &mut state_builder));
+        assert!(roles.has_role(&ACCOUNT, Role::Minter));
+        assert!(!roles.has_role(&ACCOUNT, Role::Admin));
+
+        assert!(roles.revoke(&ACCOUNT, Role::Minter));
+        assert!(!roles.revoke(&ACCOUNT, Role::Minter));
+        assert!(!roles.has_role(&ACCOUNT, Role::Minter));
+        assert!(roles.has_role(&ADMIN, Role::Admin));
+    }
+
+    #[concordium_test]
+    fn should_transfer_admin() {
+        let mut state_builder = TestStateBuilder::new();
+        let mut roles = Roles::new(ADMIN, &mut state_builder);
+
+        // Only the proposed admin can accept.
+        assert_eq!(roles.accept_admin(&ACCOUNT), None);
+        roles.propose_admin(ACCOUNT);
+        assert_eq!(roles.accept_admin(&ADMIN), None);
+        assert_eq!(roles.admin(), ADMIN);
+        assert!(roles.has_role(&ADMIN, Role::Admin));
+
+        assert_eq!(roles.accept_admin(&ACCOUNT), Some(ADMIN));
+        assert_eq!(roles.admin(), ACCOUNT);
+        assert_eq!(roles.proposed_admin(), None);
+        assert!(roles.has_role(&ACCOUNT, Role::Admin));
+        assert!(!roles.has_role(&ADMIN, Role::Admin));
+    }
+}
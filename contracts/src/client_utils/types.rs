@@ -2,7 +2,7 @@ use concordium_cis2::*;
 use concordium_std::*;
 
 /// Type of token Id used by the CIS2 contract.
-pub type ContractTokenId = TokenIdU8;
+pub type ContractTokenId = TokenIdU32;
 /// Type of Token Amount used by the CIS2 contract.
 pub type ContractTokenAmount = TokenAmountU64;
 pub type ContractOnReceivingCis2Params =
@@ -14,22 +14,24 @@ pub struct ContractMetadataUrl {
     pub hash: Option<String>,
 }
 
-impl From<ContractMetadataUrl> for MetadataUrl {
-    fn from(val: ContractMetadataUrl) -> Self {
-        MetadataUrl {
-            url: val.url,
-            hash: {
-                if let Some(hash) = val.hash {
-                    let mut hash_bytes = [0u8; 32];
-                    match hex::decode_to_slice(hash, &mut hash_bytes) {
-                        Ok(_) => Some(hash_bytes),
-                        Err(_) => None,
-                    }
-                } else {
-                    None
-                }
-            },
-        }
+/// The hash of a [`ContractMetadataUrl`] is not a hex encoded SHA-256 hash.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidMetadataHash;
+
+impl TryFrom<ContractMetadataUrl> for MetadataUrl {
+    type Error = InvalidMetadataHash;
+
+    fn try_from(val: ContractMetadataUrl) -> Result<Self, Self::Error> {
+        let hash = match val.hash {
+            Some(hash) => {
+                let mut hash_bytes = [0u8; 32];
+                hex::decode_to_slice(hash, &mut hash_bytes).map_err(|_| InvalidMetadataHash)?;
+                Some(hash_bytes)
+            }
+            None => None,
+        };
+
+        Ok(MetadataUrl { url: val.url, hash })
     }
 }
 
@@ -38,7 +40,99 @@ pub struct MaturityOfQueryParams<T: IsTokenId> {
     pub queries: Vec<T>,
 }
 
-pub type MaturityOfQueryResponse = Vec<Timestamp>;
+/// Fractions of a maturity schedule are given in basis points, i.e. 10000 is
+/// 100%.
+pub const MATURITY_BASIS_POINTS: u16 = 10000;
+
+/// Tranche of a maturity schedule.
+#[derive(Deserial, Serial, SchemaType, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaturityTranche {
+    /// Time from which the tranche is mature.
+    pub time: Timestamp,
+    /// Fraction of the credits of the project in the tranche, in basis points.
+    pub fraction: u16,
+}
+
+/// Schedule by which the credits of a project mature, like a vesting schedule.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub struct MaturitySchedule {
+    /// Tranches ordered by time.
+    pub tranches: Vec<MaturityTranche>,
+}
+
+impl MaturitySchedule {
+    /// Checks that the tranches are ordered by time, are not empty and add up
+    /// to 100%.
+    pub fn is_valid(&self) -> bool {
+        let ordered = self
+            .tranches
+            .windows(2)
+            .all(|pair| pair[0].time < pair[1].time);
+        let total: u32 = self
+            .tranches
+            .iter()
+            .map(|tranche| u32::from(tranche.fraction))
+            .sum();
+
+        ordered
+            && self.tranches.iter().all(|tranche| tranche.fraction > 0)
+            && total == u32::from(MATURITY_BASIS_POINTS)
+    }
+
+    /// Fraction of the credits which has matured at the given time, in basis
+    /// points.
+    pub fn matured_fraction(&self, now: &Timestamp) -> u16 {
+        self.tranches
+            .iter()
+            .filter(|tranche| tranche.time <= *now)
+            .map(|tranche| tranche.fraction)
+            .fold(0, u16::saturating_add)
+    }
+
+    /// Checks if all of the credits have matured at the given time.
+    pub fn is_mature(&self, now: &Timestamp) -> bool {
+        self.matured_fraction(now) >= MATURITY_BASIS_POINTS
+    }
+
+    /// Time at which all of the credits have matured.
+    pub fn maturity_time(&self) -> Option<Timestamp> {
+        self.tranches.last().map(|tranche| tranche.time)
+    }
+}
+
+pub type MaturityOfQueryResponse = Vec<MaturitySchedule>;
+
+/// Range of the serial numbers under which the registry issued the credits of
+/// a project.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub struct SerialRange {
+    pub start: String,
+    pub end: String,
+}
+
+/// Registry information of a carbon project, stored on chain when the project
+/// is minted.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub struct ProjectInfo {
+    /// Year in which the emission reductions took place.
+    pub vintage: u16,
+    /// Name of the registry the project is listed in, e.g. Verra.
+    pub registry: String,
+    pub serial_range: SerialRange,
+    /// ID of the methodology the project follows, e.g. VM0015.
+    pub methodology: String,
+    /// ISO 3166-1 alpha-2 code of the country the project is located in.
+    pub country: String,
+    /// Type of the project, e.g. Reforestation.
+    pub project_type: String,
+}
+
+#[derive(Deserial, Serial, SchemaType)]
+pub struct ProjectInfoQueryParams<T: IsTokenId> {
+    pub queries: Vec<T>,
+}
+
+pub type ProjectInfoQueryResponse = Vec<ProjectInfo>;
 
 #[derive(Deserial, Serial, SchemaType)]
 pub struct IsVerifiedQueryParams<T: IsTokenId> {
@@ -47,6 +141,40 @@ pub struct IsVerifiedQueryParams<T: IsTokenId> {
 
 pub type IsVerifiedQueryResponse = Vec<bool>;
 
+/// Evidence recorded by a verifier when verifying a token.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub struct Attestation {
+    /// ID of the methodology the project was verified against, e.g. VM0015.
+    pub methodology_id: String,
+    /// Vintage or reporting period of the verified reductions.
+    pub reporting_period: ReportingPeriod,
+    pub report_url: String,
+    /// SHA-256 hash of the verification report.
+    pub report_hash: HashSha2256,
+}
+
+/// Verification of a token by a verifier.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub struct Verification {
+    pub verifier: Address,
+    pub attestation: Attestation,
+}
+
+pub type VerificationsOfQueryResponse = Vec<Vec<Verification>>;
+
+/// Verification status of a token.
+#[derive(Deserial, Serial, SchemaType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationStatus {
+    /// No verifier has verified the token.
+    Unverified,
+    /// Verified by verifiers whose weight is below the quorum.
+    Pending,
+    /// Verified by verifiers whose weight meets the quorum.
+    Verified,
+}
+
+pub type VerificationStatusQueryResponse = Vec<VerificationStatus>;
+
 #[derive(Deserial, Serial, SchemaType)]
 pub struct BurnParam<T: IsTokenId, A: IsTokenAmount> {
     pub token_id: T,
@@ -65,3 +193,134 @@ pub struct IsVerifierQueryParams {
 }
 
 pub type IsVerifierQueryResponse = Vec<bool>;
+
+/// Sequential ID of a retirement certificate, unique within a contract.
+pub type CertificateId = u64;
+
+/// On whose behalf tokens are retired.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub enum Beneficiary {
+    Name(String),
+    Address(Address),
+}
+
+/// Period of the emissions the retirement is claimed against.
+#[derive(Deserial, Serial, SchemaType, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReportingPeriod {
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+/// Details of a retirement, recorded in the retirement certificate.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub struct RetirementDetails {
+    pub beneficiary: Beneficiary,
+    /// Reason for the retirement or the claim made with it.
+    pub reason: String,
+    pub reporting_period: ReportingPeriod,
+}
+
+#[derive(Deserial, Serial, SchemaType)]
+pub struct RetireParams<T: IsTokenId, A: IsTokenAmount> {
+    pub owner: Address,
+    pub tokens: Vec<BurnParam<T, A>>,
+    pub details: RetirementDetails,
+}
+
+/// Parameter of `retireFor`, retiring the tokens of the owner on behalf of a
+/// beneficiary.
+#[derive(Deserial, Serial, SchemaType)]
+pub struct RetireForParams<T: IsTokenId, A: IsTokenAmount> {
+    pub owner: Address,
+    pub tokens: Vec<BurnParam<T, A>>,
+    pub beneficiary: Address,
+    /// Reason for the retirement or the claim made with it.
+    pub reason: String,
+    pub reporting_period: ReportingPeriod,
+}
+
+/// Proof that an amount of a token has been retired.
+#[derive(Deserial, Serial, SchemaType, Clone, Debug, PartialEq, Eq)]
+pub struct RetirementCertificate<T: IsTokenId, A: IsTokenAmount> {
+    pub certificate_id: CertificateId,
+    pub token_id: T,
+    pub amount: A,
+    pub owner: Address,
+    pub retired_at: Timestamp,
+    pub details: RetirementDetails,
+}
+
+#[derive(Deserial, Serial, SchemaType)]
+pub struct RetirementOfQueryParams {
+    pub queries: Vec<CertificateId>,
+}
+
+pub type RetirementOfQueryResponse<T, A> = Vec<RetirementCertificate<T, A>>;
+
+/// Parameter of the `addVerifierContract` and `removeVerifierContract`
+/// entrypoints.
+#[derive(Deserial, Serial, SchemaType)]
+pub struct VerifierContractParams {
+    pub contract: ContractAddress,
+}
+
+/// Event logged when a contract is added to or removed from the verifier
+/// contracts.
+#[derive(Deserial, Serial, SchemaType, Debug, PartialEq, Eq)]
+pub struct VerifierContractUpdatedEvent {
+    pub contract: ContractAddress,
+}
+
+pub type VerifierContractsQueryResponse = Vec<ContractAddress>;
+
+/// Parameter of the `setPaused` entrypoint.
+#[derive(Deserial, Serial, SchemaType)]
+pub struct SetPausedParams {
+    pub paused: bool,
+}
+
+/// Event logged when a contract is paused or unpaused.
+#[derive(Deserial, Serial, SchemaType, Debug, PartialEq, Eq)]
+pub struct PauseEvent {
+    /// The pauser which paused or unpaused the contract.
+    pub pauser: Address,
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+
+    fn tranche(time: u64, fraction: u16) -> MaturityTranche {
+        MaturityTranche {
+            time: Timestamp::from_timestamp_millis(time),
+            fraction,
+        }
+    }
+
+    #[concordium_test]
+    fn should_validate_maturity_schedule() {
+        let schedule = |tranches| MaturitySchedule { tranches };
+
+        assert!(schedule(vec![tranche(1, 10000)]).is_valid());
+        assert!(schedule(vec![tranche(1, 2500), tranche(2, 7500)]).is_valid());
+        assert!(!schedule(vec![]).is_valid());
+        assert!(!schedule(vec![tranche(1, 5000)]).is_valid());
+        assert!(!schedule(vec![tranche(2, 5000), tranche(1, 5000)]).is_valid());
+        assert!(!schedule(vec![tranche(1, 10000), tranche(2, 0)]).is_valid());
+    }
+
+    #[concordium_test]
+    fn should_mature_by_tranche() {
+        let schedule = MaturitySchedule {
+            tranches: vec![tranche(10, 2500), tranche(20, 7500)],
+        };
+        let at = Timestamp::from_timestamp_millis;
+
+        assert_eq!(schedule.matured_fraction(&at(9)), 0);
+        assert_eq!(schedule.matured_fraction(&at(10)), 2500);
+        assert!(!schedule.is_mature(&at(19)));
+        assert_eq!(schedule.matured_fraction(&at(20)), 10000);
+        assert!(schedule.is_mature(&at(20)));
+        assert_eq!(schedule.maturity_time(), Some(at(20)));
+    }
+}
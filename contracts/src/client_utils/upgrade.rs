@@ -0,0 +1,182 @@
+//! Upgrading a contract instance to a new module.
+//!
+//! The state of every contract starts with the version of its layout. The
+//! `upgrade` entrypoint of a contract upgrades the instance to a new module
+//! and optionally calls an entrypoint of the new module, usually `migrate`.
+//! The migration reads the version with [`state_version`] to know the layout
+//! the state has been stored with, and writes the state with the layout and
+//! version of the new module.
+//!
+//! Version 1 is the layout of the first module released with an `upgrade`
+//! entrypoint. Changes to the layout before that release keep version 1. Any
+//! later change increases the version, and the new module gets a `migrate`
+//! entrypoint which migrates the previous version.
+//!
+//! The `upgrade` entrypoints are low level, as a high level host deserializes
+//! the state again after the migration with the layout of the old module.
+
+use concordium_std::*;
+
+/// Version of the layout of the state of a contract.
+pub type StateVersion = u32;
+
+/// Parameter of the `upgrade` entrypoint.
+#[derive(Serialize, SchemaType)]
+pub struct UpgradeParams {
+    /// The new module of the contract.
+    pub module: ModuleReference,
+    /// Entrypoint of the new module, with its parameter, which is called
+    /// after the upgrade to migrate the state.
+    pub migrate: Option<(OwnedEntrypointName, OwnedParameter)>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, SchemaType)]
+pub enum ContractUpgradeError {
+    /// The instance could not be upgraded to the module.
+    FailedUpgrade,
+    /// The migration entrypoint of the new module failed.
+    FailedMigration,
+}
+
+/// Reads the version of the state, without reading the rest of the state.
+pub fn state_version<S: HasStateApi>(state_api: &S) -> ParseResult<StateVersion> {
+    state_api
+        .lookup_entry(&[])
+        .ok_or_else(ParseError::default)?
+        .get()
+}
+
+/// Upgrades the instance to the module and calls the migration entrypoint of
+/// the new module, if any.
+pub fn upgrade<State>(
+    self_address: &ContractAddress,
+    host: &mut impl HasHost<State>,
+    params: UpgradeParams,
+) -> Result<(), ContractUpgradeError> {
+    host.upgrade(params.module)
+        .map_err(|_| ContractUpgradeError::FailedUpgrade)?;
+
+    if let Some((entrypoint, parameter)) = params.migrate {
+        host.invoke_contract_raw(
+            self_address,
+            parameter.as_parameter(),
+            entrypoint.as_entrypoint_name(),
+            Amount::zero(),
+        )
+        .map_err(|_| ContractUpgradeError::FailedMigration)?;
+    }
+
+    Ok(())
+}
+
+/// Helpers to test the low level `upgrade` entrypoints.
+#[concordium_cfg_test]
+pub(crate) mod test_utils {
+    use concordium_std::{test_infrastructure::*, *};
+
+    /// A host of the raw state of a contract. Everything but the state is
+    /// handled by `host`, e.g. the mocks of upgrades and entrypoints.
+    pub struct LowLevelTestHost {
+        pub host: TestHost<()>,
+        state_api: TestStateApi,
+        state_builder: TestStateBuilder,
+    }
+
+    impl LowLevelTestHost {
+        /// A host with an empty state. Write the state of the contract with
+        /// `state_mut().write_root`.
+        pub fn new() -> Self {
+            let state_api = TestStateApi::new();
+            LowLevelTestHost {
+                host: TestHost::new((), TestStateBuilder::new()),
+                state_builder: StateBuilder::open(state_api.clone()),
+                state_api,
+            }
+        }
+    }
+
+    impl HasHost<TestStateApi> for LowLevelTestHost {
+        type ReturnValueType = <TestHost<()> as HasHost<()>>::ReturnValueType;
+        type StateApiType = TestStateApi;
+
+        fn invoke_transfer(&self, receiver: &AccountAddress, amount: Amount) -> TransferResult {
+            self.host.invoke_transfer(receiver, amount)
+        }
+
+        fn invoke_contract_raw(
+            &mut self,
+            to: &ContractAddress,
+            parameter: Parameter,
+            method: EntrypointName,
+            amount: Amount,
+        ) -> CallContractResult<Self::ReturnValueType> {
+            self.host.invoke_contract_raw(to, parameter, method, amount)
+        }
+
+        fn upgrade(&mut self, module: ModuleReference) -> UpgradeResult {
+            self.host.upgrade(module)
+        }
+
+        fn invoke_contract_raw_read_only(
+            &self,
+            to: &ContractAddress,
+            parameter: Parameter<'_>,
+            method: EntrypointName<'_>,
+            amount: Amount,
+        ) -> ReadOnlyCallContractResult<Self::ReturnValueType> {
+            self.host
+                .invoke_contract_raw_read_only(to, parameter, method, amount)
+        }
+
+        fn exchange_rates(&self) -> ExchangeRates {
+            self.host.exchange_rates()
+        }
+
+        fn account_balance(&self, address: AccountAddress) -> QueryAccountBalanceResult {
+            self.host.account_balance(address)
+        }
+
+        fn contract_balance(&self, address: ContractAddress) -> QueryContractBalanceResult {
+            self.host.contract_balance(address)
+        }
+
+        fn state(&self) -> &TestStateApi {
+            &self.state_api
+        }
+
+        fn state_mut(&mut self) -> &mut TestStateApi {
+            &mut self.state_api
+        }
+
+        // The raw state is written directly.
+        fn commit_state(&mut self) {}
+
+        fn state_builder(&mut self) -> &mut TestStateBuilder {
+            &mut self.state_builder
+        }
+
+        fn state_and_builder(&mut self) -> (&mut TestStateApi, &mut TestStateBuilder) {
+            (&mut self.state_api, &mut self.state_builder)
+        }
+
+        fn self_balance(&self) -> Amount {
+            self.host.self_balance()
+        }
+    }
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_read_state_version() {
+        let mut state_api = TestStateApi::new();
+        assert!(state_version(&state_api).is_err());
+
+        // The version is read without the rest of the state.
+        state_api.write_root(&(7 as StateVersion, String::from("state")));
+        assert_eq!(state_version(&state_api), Ok(7));
+    }
+}
@@ -0,0 +1,519 @@
+//! Decoding of the events logged by the contracts, for off-chain consumers.
+
+use concordium_std::*;
+
+use crate::{carbon_credit_market, carbon_credits, project_token};
+
+/// Name of the Project Token contract.
+pub const PROJECT_TOKEN: &str = "project_token";
+/// Name of the Carbon Credits contract.
+pub const CARBON_CREDITS: &str = "carbon_credits";
+/// Name of the Carbon Credit Market contract.
+pub const CARBON_CREDIT_MARKET: &str = "carbon_credit_market";
+
+/// An event logged by one of the contracts.
+pub enum ContractEvent {
+    ProjectToken(project_token::events::ContractEvent),
+    CarbonCredits(carbon_credits::events::ContractEvent),
+    CarbonCreditMarket(carbon_credit_market::events::ContractEvent),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The contract is not one of the contracts of this crate.
+    UnknownContract,
+    /// The event has an unknown tag or is not a valid event of the contract.
+    InvalidEvent,
+}
+
+/// Decodes an event from the raw bytes logged by the contract with the given
+/// name.
+pub fn decode_event(contract_name: &str, bytes: &[u8]) -> Result<ContractEvent, DecodeError> {
+    let mut cursor = Cursor::new(bytes);
+    let event = match contract_name {
+        PROJECT_TOKEN => cursor.get().map(ContractEvent::ProjectToken),
+        CARBON_CREDITS => cursor.get().map(ContractEvent::CarbonCredits),
+        CARBON_CREDIT_MARKET => cursor.get().map(ContractEvent::CarbonCreditMarket),
+        _ => return Err(DecodeError::UnknownContract),
+    }
+    .map_err(|_| DecodeError::InvalidEvent)?;
+    // The whole log must be a single event.
+    ensure_eq!(cursor.offset, bytes.len(), DecodeError::InvalidEvent);
+
+    Ok(event)
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::client_utils::{event_tags::*, roles::*, types::*};
+    use concordium_cis2::*;
+    use concordium_std::{
+        collections::BTreeSet,
+        schema::{SchemaType, Type},
+    };
+
+    const ACCOUNT_0: AccountAddress = AccountAddress([0u8; 32]);
+    const ADDRESS_0: Address = Address::Account(ACCOUNT_0);
+    const CONTRACT_ADDRESS: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+
+    fn retirement_details(beneficiary: Beneficiary) -> RetirementDetails {
+        RetirementDetails {
+            beneficiary,
+            reason: "Offsetting 2023 emissions".to_string(),
+            reporting_period: ReportingPeriod {
+                start: Timestamp::from_timestamp_millis(1),
+                end: Timestamp::from_timestamp_millis(2),
+            },
+        }
+    }
+
+    fn role_updated() -> RoleUpdatedEvent {
+        RoleUpdatedEvent {
+            address: ADDRESS_0,
+            role: Role::Minter,
+        }
+    }
+
+    fn admin_proposed() -> AdminProposedEvent {
+        AdminProposedEvent {
+            admin: ADDRESS_0,
+            proposed_admin: Address::Contract(CONTRACT_ADDRESS),
+        }
+    }
+
+    fn admin_transferred() -> AdminTransferredEvent {
+        AdminTransferredEvent {
+            previous_admin: ADDRESS_0,
+            admin: Address::Contract(CONTRACT_ADDRESS),
+        }
+    }
+
+    fn pause() -> PauseEvent {
+        PauseEvent { pauser: ADDRESS_0 }
+    }
+
+    fn verifier_contract_updated() -> VerifierContractUpdatedEvent {
+        VerifierContractUpdatedEvent {
+            contract: CONTRACT_ADDRESS,
+        }
+    }
+
+    fn event_bytes(event: &ContractEvent) -> Vec<u8> {
+        match event {
+            ContractEvent::ProjectToken(event) => to_bytes(event),
+            ContractEvent::CarbonCredits(event) => to_bytes(event),
+            ContractEvent::CarbonCreditMarket(event) => to_bytes(event),
+        }
+    }
+
+    /// Asserts that the event is logged with the tag and decodes to the same
+    /// event.
+    fn assert_round_trip(contract_name: &str, bytes: Vec<u8>, tag: u8) {
+        assert_eq!(bytes[0], tag);
+        let event = decode_event(contract_name, &bytes);
+        assert!(event.is_ok(), "Failed to decode event with tag {}", tag);
+        assert_eq!(event_bytes(&event.ok().unwrap()), bytes);
+    }
+
+    /// An event of every variant with the tag it is logged with.
+    fn project_token_events() -> Vec<(project_token::events::ContractEvent, u8)> {
+        use project_token::events::*;
+
+        let token_id = TokenIdU32(1);
+        let burn = || BurnEvent {
+            token_id,
+            amount: TokenAmountU8(1),
+            owner: ADDRESS_0,
+        };
+        let verifier_updated = || VerifierUpdatedEvent {
+            verifier: ADDRESS_0,
+        };
+        vec![
+            (
+                ContractEvent::Transfer(TransferEvent {
+                    token_id,
+                    amount: TokenAmountU8(1),
+                    from: ADDRESS_0,
+                    to: Address::Contract(CONTRACT_ADDRESS),
+                }),
+                TRANSFER_EVENT_TAG,
+            ),
+            (
+                ContractEvent::Mint(MintEvent {
+                    token_id,
+                    amount: TokenAmountU8(1),
+                    owner: ADDRESS_0,
+                }),
+                MINT_EVENT_TAG,
+            ),
+            (
+                ContractEvent::UpdateOperator(UpdateOperatorEvent {
+                    update: OperatorUpdate::Add,
+                    owner: ADDRESS_0,
+                    operator: Address::Contract(CONTRACT_ADDRESS),
+                }),
+                UPDATE_OPERATOR_EVENT_TAG,
+            ),
+            (
+                ContractEvent::TokenMetadata(TokenMetadataEvent {
+                    token_id,
+                    metadata_url: MetadataUrl {
+                        url: "https://example.com/1.json".to_string(),
+                        hash: Some([1u8; 32]),
+                    },
+                }),
+                TOKEN_METADATA_EVENT_TAG,
+            ),
+            (ContractEvent::Retire(burn()), RETIRE_EVENT_TAG),
+            (ContractEvent::Retract(burn()), RETRACT_EVENT_TAG),
+            (ContractEvent::Burn(burn()), BURN_EVENT_TAG),
+            (
+                ContractEvent::MaturityTime(MaturityTimeEvent {
+                    token_id,
+                    maturity: MaturitySchedule {
+                        tranches: vec![MaturityTranche {
+                            time: Timestamp::from_timestamp_millis(1),
+                            fraction: MATURITY_BASIS_POINTS,
+                        }],
+                    },
+                }),
+                MATURITY_TIME_EVENT_TAG,
+            ),
+            (
+                ContractEvent::VerifierAdded(verifier_updated()),
+                VERIFIER_ADDED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::VerifierRemoved(verifier_updated()),
+                VERIFIER_REMOVED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::Verification(VerificationEvent {
+                    verifier: ADDRESS_0,
+                    token_id,
+                }),
+                VERIFICATION_EVENT_TAG,
+            ),
+            (
+                ContractEvent::RetirementCertificate(RetirementCertificateEvent {
+                    certificate_id: 1,
+                    token_id,
+                    amount: TokenAmountU8(1),
+                    owner: ADDRESS_0,
+                    retired_at: Timestamp::from_timestamp_millis(3),
+                    details: retirement_details(Beneficiary::Name("ACME".to_string())),
+                }),
+                RETIREMENT_CERTIFICATE_EVENT_TAG,
+            ),
+            (
+                ContractEvent::QuorumUpdated(QuorumUpdatedEvent {
+                    threshold: 2,
+                    weights: vec![VerifierWeight {
+                        verifier: ADDRESS_0,
+                        weight: 2,
+                    }],
+                }),
+                QUORUM_UPDATED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::VerificationRevoked(VerificationRevokedEvent {
+                    token_id,
+                    verifier: ADDRESS_0,
+                    revoked_by: Address::Contract(CONTRACT_ADDRESS),
+                    reason: RevocationReason::Fraud,
+                }),
+                VERIFICATION_REVOKED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::VerificationsAffected(VerificationsAffectedEvent {
+                    verifier: ADDRESS_0,
+                    token_ids: vec![token_id, TokenIdU32(2)],
+                    invalidated: true,
+                }),
+                VERIFICATIONS_AFFECTED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::RoleGranted(role_updated()),
+                ROLE_GRANTED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::RoleRevoked(role_updated()),
+                ROLE_REVOKED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::AdminProposed(admin_proposed()),
+                ADMIN_PROPOSED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::AdminTransferred(admin_transferred()),
+                ADMIN_TRANSFERRED_EVENT_TAG,
+            ),
+            (ContractEvent::Paused(pause()), PAUSED_EVENT_TAG),
+            (ContractEvent::Unpaused(pause()), UNPAUSED_EVENT_TAG),
+            (
+                ContractEvent::MetadataFrozen(MetadataFrozenEvent { token_id }),
+                METADATA_FROZEN_EVENT_TAG,
+            ),
+            (
+                ContractEvent::MaturityAmended(MaturityAmendedEvent {
+                    token_id,
+                    verifier: ADDRESS_0,
+                    reason: "Delayed planting".to_string(),
+                }),
+                MATURITY_AMENDED_EVENT_TAG,
+            ),
+        ]
+    }
+
+    /// An event of every variant with the tag it is logged with.
+    fn carbon_credits_events() -> Vec<(carbon_credits::events::ContractEvent, u8)> {
+        use carbon_credits::events::*;
+
+        let token_id = TokenIdU32(1);
+        let burn = || BurnEvent {
+            token_id,
+            amount: TokenAmountU64(1),
+            owner: ADDRESS_0,
+        };
+        let collateral_updated = || CollateralUpdatedEvent {
+            contract: CONTRACT_ADDRESS,
+            token_id,
+            amount: TokenAmountU8(1),
+            owner: ADDRESS_0,
+        };
+        vec![
+            (
+                ContractEvent::Transfer(TransferEvent {
+                    token_id,
+                    amount: TokenAmountU64(1),
+                    from: ADDRESS_0,
+                    to: Address::Contract(CONTRACT_ADDRESS),
+                }),
+                TRANSFER_EVENT_TAG,
+            ),
+            (
+                ContractEvent::Mint(MintEvent {
+                    token_id,
+                    amount: TokenAmountU64(1),
+                    owner: ADDRESS_0,
+                }),
+                MINT_EVENT_TAG,
+            ),
+            (
+                ContractEvent::UpdateOperator(UpdateOperatorEvent {
+                    update: OperatorUpdate::Remove,
+                    owner: ADDRESS_0,
+                    operator: Address::Contract(CONTRACT_ADDRESS),
+                }),
+                UPDATE_OPERATOR_EVENT_TAG,
+            ),
+            (
+                ContractEvent::TokenMetadata(TokenMetadataEvent {
+                    token_id,
+                    metadata_url: MetadataUrl {
+                        url: "https://example.com/1.json".to_string(),
+                        hash: None,
+                    },
+                }),
+                TOKEN_METADATA_EVENT_TAG,
+            ),
+            (ContractEvent::Retire(burn()), RETIRE_EVENT_TAG),
+            (ContractEvent::Retract(burn()), RETRACT_EVENT_TAG),
+            (ContractEvent::Burn(burn()), BURN_EVENT_TAG),
+            (
+                ContractEvent::CollateralAdded(collateral_updated()),
+                COLLATERAL_ADDED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::CollateralRemoved(collateral_updated()),
+                COLLATERAL_REMOVED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::CollateralUsedEvent(collateral_updated()),
+                COLLATERAL_USED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::RetirementCertificate(RetirementCertificateEvent {
+                    certificate_id: 1,
+                    token_id,
+                    amount: TokenAmountU64(1),
+                    owner: ADDRESS_0,
+                    retired_at: Timestamp::from_timestamp_millis(3),
+                    details: retirement_details(Beneficiary::Address(Address::Contract(
+                        CONTRACT_ADDRESS,
+                    ))),
+                }),
+                RETIREMENT_CERTIFICATE_EVENT_TAG,
+            ),
+            (
+                ContractEvent::RoleGranted(role_updated()),
+                ROLE_GRANTED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::RoleRevoked(role_updated()),
+                ROLE_REVOKED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::AdminProposed(admin_proposed()),
+                ADMIN_PROPOSED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::AdminTransferred(admin_transferred()),
+                ADMIN_TRANSFERRED_EVENT_TAG,
+            ),
+            (ContractEvent::Paused(pause()), PAUSED_EVENT_TAG),
+            (ContractEvent::Unpaused(pause()), UNPAUSED_EVENT_TAG),
+            (
+                ContractEvent::VerifierContractAdded(verifier_contract_updated()),
+                VERIFIER_CONTRACT_ADDED_EVENT_TAG,
+            ),
+            (
+                ContractEvent::VerifierContractRemoved(verifier_contract_updated()),
+                VERIFIER_CONTRACT_REMOVED_EVENT_TAG,
+            ),
+        ]
+    }
+
+    /// Asserts that no two events share a tag and that the schema has exactly
+    /// the tags of the events.
+    fn assert_unique_tags(tags: Vec<u8>, schema: Type) {
+        let unique_tags: BTreeSet<u8> = tags.iter().copied().collect();
+        assert_eq!(unique_tags.len(), tags.len(), "Events share a tag");
+        match schema {
+            Type::TaggedEnum(variants) => {
+                assert_eq!(
+                    variants.keys().copied().collect::<BTreeSet<u8>>(),
+                    unique_tags
+                )
+            }
+            _ => fail!("Events should have a tagged enum schema"),
+        }
+    }
+
+    #[concordium_test]
+    fn should_decode_project_token_events() {
+        for (event, tag) in project_token_events() {
+            assert_round_trip(PROJECT_TOKEN, to_bytes(&event), tag);
+        }
+    }
+
+    #[concordium_test]
+    fn should_decode_carbon_credits_events() {
+        for (event, tag) in carbon_credits_events() {
+            assert_round_trip(CARBON_CREDITS, to_bytes(&event), tag);
+        }
+    }
+
+    #[concordium_test]
+    fn should_have_unique_event_tags() {
+        assert_unique_tags(
+            project_token_events()
+                .iter()
+                .map(|(event, _)| to_bytes(event)[0])
+                .collect(),
+            project_token::events::ContractEvent::get_type(),
+        );
+        assert_unique_tags(
+            carbon_credits_events()
+                .iter()
+                .map(|(event, _)| to_bytes(event)[0])
+                .collect(),
+            carbon_credits::events::ContractEvent::get_type(),
+        );
+    }
+
+    #[concordium_test]
+    fn should_decode_carbon_credit_market_events() {
+        use carbon_credit_market::events::*;
+
+        let token_id = TokenIdU32(1);
+        let events = vec![
+            ContractEvent::TokenReceived(TokenReceivedEvent {
+                token_id,
+                token_contract: CONTRACT_ADDRESS,
+                owner: ADDRESS_0,
+                amount: TokenAmountU64(1),
+            }),
+            ContractEvent::TokenListed(TokenListedEvent {
+                token_id,
+                token_contract: CONTRACT_ADDRESS,
+                amount: TokenAmountU64(1),
+                price: Amount::from_ccd(1),
+            }),
+            ContractEvent::TokenTransferred(TokenTransferredEvent {
+                token_id,
+                token_contract: CONTRACT_ADDRESS,
+                from: ADDRESS_0,
+                to: Address::Contract(CONTRACT_ADDRESS),
+                amount: TokenAmountU64(1),
+            }),
+            ContractEvent::TokenDelisted(TokenDelistedEvent {
+                token_id,
+                token_contract: CONTRACT_ADDRESS,
+                owner: ADDRESS_0,
+            }),
+            ContractEvent::TokenWithdrawn(TokenWithdrawnEvent {
+                token_id,
+                token_contract: CONTRACT_ADDRESS,
+                owner: ADDRESS_0,
+                amount: TokenAmountU64(1),
+            }),
+            ContractEvent::RoleGranted(role_updated()),
+            ContractEvent::RoleRevoked(role_updated()),
+            ContractEvent::CommissionUpdated(CommissionUpdatedEvent { commission: 250 }),
+            ContractEvent::AdminProposed(admin_proposed()),
+            ContractEvent::AdminTransferred(admin_transferred()),
+            ContractEvent::Paused(pause()),
+            ContractEvent::Unpaused(pause()),
+            ContractEvent::VerifierContractAdded(verifier_contract_updated()),
+            ContractEvent::VerifierContractRemoved(verifier_contract_updated()),
+            ContractEvent::CommissionRecipientUpdated(CommissionRecipientUpdatedEvent {
+                recipient: ACCOUNT_0,
+            }),
+            ContractEvent::TokenSold(TokenSoldEvent {
+                token_id,
+                token_contract: CONTRACT_ADDRESS,
+                seller: ADDRESS_0,
+                buyer: Address::Contract(CONTRACT_ADDRESS),
+                amount: TokenAmountU64(1),
+                paid: Amount::from_ccd(1),
+                commission: Amount::from_micro_ccd(25_000),
+            }),
+        ];
+
+        // Tags of the Market events are derived from the order of the variants.
+        for (tag, event) in events.into_iter().enumerate() {
+            assert_round_trip(CARBON_CREDIT_MARKET, to_bytes(&event), tag as u8);
+        }
+    }
+
+    #[concordium_test]
+    fn should_not_decode_invalid_events() {
+        assert_eq!(
+            decode_event("other", &[0]).err(),
+            Some(DecodeError::UnknownContract)
+        );
+        for contract_name in [PROJECT_TOKEN, CARBON_CREDITS, CARBON_CREDIT_MARKET] {
+            assert_eq!(
+                decode_event(contract_name, &[100]).err(),
+                Some(DecodeError::InvalidEvent)
+            );
+        }
+
+        // A valid event followed by an extra byte.
+        let mut bytes = to_bytes(&project_token::events::ContractEvent::VerifierAdded(
+            project_token::events::VerifierUpdatedEvent {
+                verifier: ADDRESS_0,
+            },
+        ));
+        bytes.push(0);
+        assert_eq!(
+            decode_event(PROJECT_TOKEN, &bytes).err(),
+            Some(DecodeError::InvalidEvent)
+        );
+    }
+}
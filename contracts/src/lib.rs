@@ -2,4 +2,6 @@
 pub mod project_token;
 pub mod carbon_credits;
 pub mod client_utils;
-pub mod carbon_credit_market;
\ No newline at end of file
+pub mod carbon_credit_market;
+#[cfg(feature = "std")]
+pub mod decode;
@@ -1,13 +1,24 @@
 use crate::{
-    client_utils::types::{IsVerifiedQueryParams, MaturityOfQueryParams, BurnParams, BurnParam},
+    client_utils::types::{
+        BurnParam, BurnParams, IsVerifiedQueryParams, MaturityOfQueryParams,
+        ProjectInfoQueryParams, RetireForParams, RetireParams, RetirementCertificate,
+        RetirementOfQueryResponse,
+    },
     project_token::error::*,
 };
 use concordium_cis2::*;
 
-pub type ContractTokenId = TokenIdU8;
+pub type ContractTokenId = TokenIdU32;
 pub type ContractTokenAmount = TokenAmountU8;
 pub type ContractResult<A> = Result<A, ContractError>;
 pub type ContractIsVerifiedQueryParams = IsVerifiedQueryParams<ContractTokenId>;
 pub type ContractMaturityOfQueryParams = MaturityOfQueryParams<ContractTokenId>;
+pub type ContractProjectInfoQueryParams = ProjectInfoQueryParams<ContractTokenId>;
 pub type ContractBurnParams = BurnParams<ContractTokenId, ContractTokenAmount>;
-pub type ContractBurnParam = BurnParam<ContractTokenId, ContractTokenAmount>;
\ No newline at end of file
+pub type ContractBurnParam = BurnParam<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetireParams = RetireParams<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetireForParams = RetireForParams<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetirementCertificate =
+    RetirementCertificate<ContractTokenId, ContractTokenAmount>;
+pub type ContractRetirementOfQueryResponse =
+    RetirementOfQueryResponse<ContractTokenId, ContractTokenAmount>;
\ No newline at end of file
@@ -1,6 +1,8 @@
 use concordium_cis2::Cis2Error;
 use concordium_std::*;
 
+use crate::client_utils::{types::InvalidMetadataHash, upgrade::ContractUpgradeError};
+
 pub type ContractError = Cis2Error<CustomContractError>;
 
 /// The different errors the contract can produce.
@@ -28,7 +30,28 @@ pub enum CustomContractError {
     /// Metadata hash is invalid.
     InvalidMetadataHash,
     /// Token is verified.
-    TokenVerifiedOrMature
+    TokenVerifiedOrMature,
+    /// Reporting period ends before it starts.
+    InvalidReportingPeriod,
+    /// Retirement certificate does not exist.
+    InvalidCertificateId,
+    /// Quorum threshold is zero.
+    InvalidQuorum,
+    /// Contract is paused.
+    Paused,
+    /// Upgrading the contract or migrating its state failed.
+    ContractUpgradeError(ContractUpgradeError),
+    /// All token IDs have been allocated.
+    TokenIdsExhausted,
+    /// Metadata hash is required but missing.
+    MissingMetadataHash,
+    /// Token metadata is frozen by its first verification.
+    MetadataFrozen,
+    /// Maturity schedule is not ordered by time or does not add up to 100%.
+    InvalidMaturitySchedule,
+    /// The role cannot be revoked with `revokeRole`. Verifiers are removed
+    /// with `removeVerifier`.
+    InvalidRole,
 }
 
 /// Mapping the logging errors to ContractError.
@@ -66,3 +89,15 @@ impl From<NewContractNameError> for CustomContractError {
         Self::InvalidContractName
     }
 }
+
+impl From<ContractUpgradeError> for ContractError {
+    fn from(ue: ContractUpgradeError) -> Self {
+        Cis2Error::Custom(CustomContractError::ContractUpgradeError(ue))
+    }
+}
+
+impl From<InvalidMetadataHash> for ContractError {
+    fn from(_: InvalidMetadataHash) -> Self {
+        Cis2Error::Custom(CustomContractError::InvalidMetadataHash)
+    }
+}
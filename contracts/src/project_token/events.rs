@@ -1,50 +1,133 @@
-use concordium_cis2::{MetadataUrl, MINT_EVENT_TAG, TOKEN_METADATA_EVENT_TAG, TRANSFER_EVENT_TAG, BURN_EVENT_TAG};
+use concordium_cis2::{
+    MetadataUrl, OperatorUpdate, UpdateOperatorEvent, BURN_EVENT_TAG, MINT_EVENT_TAG,
+    TOKEN_METADATA_EVENT_TAG, TRANSFER_EVENT_TAG, UPDATE_OPERATOR_EVENT_TAG,
+};
 use concordium_std::{collections::BTreeMap, schema::SchemaType, *};
 
 use super::contract_types::*;
+use crate::client_utils::{
+    event_tags::{
+        ADMIN_PROPOSED_EVENT_TAG, ADMIN_TRANSFERRED_EVENT_TAG, MATURITY_AMENDED_EVENT_TAG,
+        MATURITY_TIME_EVENT_TAG, METADATA_FROZEN_EVENT_TAG, PAUSED_EVENT_TAG,
+        QUORUM_UPDATED_EVENT_TAG, RETIREMENT_CERTIFICATE_EVENT_TAG, RETIRE_EVENT_TAG,
+        RETRACT_EVENT_TAG, ROLE_GRANTED_EVENT_TAG, ROLE_REVOKED_EVENT_TAG, UNPAUSED_EVENT_TAG,
+        VERIFICATIONS_AFFECTED_EVENT_TAG, VERIFICATION_EVENT_TAG, VERIFICATION_REVOKED_EVENT_TAG,
+        VERIFIER_ADDED_EVENT_TAG, VERIFIER_REMOVED_EVENT_TAG,
+    },
+    roles::{AdminProposedEvent, AdminTransferredEvent, Role, RoleUpdatedEvent},
+    types::{CertificateId, MaturitySchedule, PauseEvent, RetirementDetails},
+};
 
 pub type MintEvent = concordium_cis2::MintEvent<ContractTokenId, ContractTokenAmount>;
 pub type TransferEvent = concordium_cis2::TransferEvent<ContractTokenId, ContractTokenAmount>;
 pub type TokenMetadataEvent = concordium_cis2::TokenMetadataEvent<ContractTokenId>;
 pub type BurnEvent = concordium_cis2::BurnEvent<ContractTokenId, ContractTokenAmount>;
+pub type RetirementCertificateEvent = ContractRetirementCertificate;
 
-#[derive(Serial, SchemaType)]
+#[derive(Serial, Deserial, SchemaType)]
 pub struct MaturityTimeEvent {
     pub token_id: ContractTokenId,
-    pub maturity_time: Timestamp,
+    pub maturity: MaturitySchedule,
 }
 
-#[derive(Serial, SchemaType)]
+/// The maturity schedule of a token was amended by one of its verifiers or by
+/// the admin. Logged together with a `MaturityTime` event with the new
+/// schedule.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct MaturityAmendedEvent {
+    pub token_id: ContractTokenId,
+    pub verifier: Address,
+    /// Why the schedule was amended, e.g. the project timeline slipped.
+    pub reason: String,
+}
+
+/// The metadata of a token was frozen by its first verification and can no
+/// longer be updated.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct MetadataFrozenEvent {
+    pub token_id: ContractTokenId,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
 pub struct VerifierUpdatedEvent {
     pub verifier: Address,
 }
 
-#[derive(Serial, SchemaType)]
+#[derive(Serial, Deserial, SchemaType)]
 pub struct VerificationEvent {
     pub verifier: Address,
     pub token_id: ContractTokenId,
 }
 
+/// Why a verification was revoked.
+#[derive(Serial, Deserial, SchemaType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RevocationReason {
+    /// The project or its documentation is fraudulent.
+    Fraud,
+    /// The verification was made in error.
+    Error,
+    /// The project no longer meets the methodology.
+    NonCompliance,
+    Other,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
+pub struct VerificationRevokedEvent {
+    pub token_id: ContractTokenId,
+    /// Verifier whose verification was revoked.
+    pub verifier: Address,
+    pub revoked_by: Address,
+    pub reason: RevocationReason,
+}
+
+/// Tokens verified by a verifier which was removed.
+#[derive(Serial, Deserial, SchemaType)]
+pub struct VerificationsAffectedEvent {
+    pub verifier: Address,
+    pub token_ids: Vec<ContractTokenId>,
+    /// Whether the verifications of the tokens were invalidated or kept.
+    pub invalidated: bool,
+}
+
+#[derive(Serial, Deserial, SchemaType, Clone, Copy)]
+pub struct VerifierWeight {
+    pub verifier: Address,
+    pub weight: u32,
+}
+
+#[derive(Serial, Deserial, SchemaType)]
+pub struct QuorumUpdatedEvent {
+    pub threshold: u32,
+    /// Weights of the verifiers which were updated.
+    pub weights: Vec<VerifierWeight>,
+}
+
 pub enum ContractEvent {
     Mint(MintEvent),
     TokenMetadata(TokenMetadataEvent),
     MaturityTime(MaturityTimeEvent),
     Transfer(TransferEvent),
+    UpdateOperator(UpdateOperatorEvent),
     Retire(BurnEvent),
     Retract(BurnEvent),
     Burn(BurnEvent),
     VerifierAdded(VerifierUpdatedEvent),
     VerifierRemoved(VerifierUpdatedEvent),
     Verification(VerificationEvent),
+    RetirementCertificate(RetirementCertificateEvent),
+    QuorumUpdated(QuorumUpdatedEvent),
+    VerificationRevoked(VerificationRevokedEvent),
+    VerificationsAffected(VerificationsAffectedEvent),
+    RoleGranted(RoleUpdatedEvent),
+    RoleRevoked(RoleUpdatedEvent),
+    AdminProposed(AdminProposedEvent),
+    AdminTransferred(AdminTransferredEvent),
+    Paused(PauseEvent),
+    Unpaused(PauseEvent),
+    MetadataFrozen(MetadataFrozenEvent),
+    MaturityAmended(MaturityAmendedEvent),
 }
 
-const RETIRE_EVENT_TAG: u8 = u8::MIN;
-const MATURITY_TIME_EVENT_TAG: u8 = u8::MIN + 1;
-const VERIFIER_ADDED_EVENT_TAG: u8 = u8::MIN + 2;
-const VERIFIER_REMOVED_EVENT_TAG: u8 = u8::MIN + 3;
-const VERIFICATION_EVENT_TAG: u8 = u8::MIN + 4;
-const RETRACT_EVENT_TAG: u8 = u8::MIN + 5;
-
 impl Serial for ContractEvent {
     fn serial<W: Write>(&self, out: &mut W) -> Result<(), W::Err> {
         match self {
@@ -56,6 +139,10 @@ impl Serial for ContractEvent {
                 out.write_u8(concordium_cis2::MINT_EVENT_TAG)?;
                 event.serial(out)
             }
+            ContractEvent::UpdateOperator(event) => {
+                out.write_u8(UPDATE_OPERATOR_EVENT_TAG)?;
+                event.serial(out)
+            }
             ContractEvent::TokenMetadata(event) => {
                 out.write_u8(concordium_cis2::TOKEN_METADATA_EVENT_TAG)?;
                 event.serial(out)
@@ -88,6 +175,89 @@ impl Serial for ContractEvent {
                 out.write_u8(VERIFICATION_EVENT_TAG)?;
                 event.serial(out)
             }
+            ContractEvent::RetirementCertificate(event) => {
+                out.write_u8(RETIREMENT_CERTIFICATE_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::QuorumUpdated(event) => {
+                out.write_u8(QUORUM_UPDATED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::VerificationRevoked(event) => {
+                out.write_u8(VERIFICATION_REVOKED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::VerificationsAffected(event) => {
+                out.write_u8(VERIFICATIONS_AFFECTED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::RoleGranted(event) => {
+                out.write_u8(ROLE_GRANTED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::RoleRevoked(event) => {
+                out.write_u8(ROLE_REVOKED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::AdminProposed(event) => {
+                out.write_u8(ADMIN_PROPOSED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::AdminTransferred(event) => {
+                out.write_u8(ADMIN_TRANSFERRED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::Paused(event) => {
+                out.write_u8(PAUSED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::Unpaused(event) => {
+                out.write_u8(UNPAUSED_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::MetadataFrozen(event) => {
+                out.write_u8(METADATA_FROZEN_EVENT_TAG)?;
+                event.serial(out)
+            }
+            ContractEvent::MaturityAmended(event) => {
+                out.write_u8(MATURITY_AMENDED_EVENT_TAG)?;
+                event.serial(out)
+            }
+        }
+    }
+}
+
+impl Deserial for ContractEvent {
+    fn deserial<R: Read>(source: &mut R) -> ParseResult<Self> {
+        match source.read_u8()? {
+            TRANSFER_EVENT_TAG => Ok(ContractEvent::Transfer(source.get()?)),
+            MINT_EVENT_TAG => Ok(ContractEvent::Mint(source.get()?)),
+            UPDATE_OPERATOR_EVENT_TAG => Ok(ContractEvent::UpdateOperator(source.get()?)),
+            TOKEN_METADATA_EVENT_TAG => Ok(ContractEvent::TokenMetadata(source.get()?)),
+            RETIRE_EVENT_TAG => Ok(ContractEvent::Retire(source.get()?)),
+            RETRACT_EVENT_TAG => Ok(ContractEvent::Retract(source.get()?)),
+            BURN_EVENT_TAG => Ok(ContractEvent::Burn(source.get()?)),
+            MATURITY_TIME_EVENT_TAG => Ok(ContractEvent::MaturityTime(source.get()?)),
+            VERIFIER_ADDED_EVENT_TAG => Ok(ContractEvent::VerifierAdded(source.get()?)),
+            VERIFIER_REMOVED_EVENT_TAG => Ok(ContractEvent::VerifierRemoved(source.get()?)),
+            VERIFICATION_EVENT_TAG => Ok(ContractEvent::Verification(source.get()?)),
+            RETIREMENT_CERTIFICATE_EVENT_TAG => {
+                Ok(ContractEvent::RetirementCertificate(source.get()?))
+            }
+            QUORUM_UPDATED_EVENT_TAG => Ok(ContractEvent::QuorumUpdated(source.get()?)),
+            VERIFICATION_REVOKED_EVENT_TAG => Ok(ContractEvent::VerificationRevoked(source.get()?)),
+            VERIFICATIONS_AFFECTED_EVENT_TAG => {
+                Ok(ContractEvent::VerificationsAffected(source.get()?))
+            }
+            ROLE_GRANTED_EVENT_TAG => Ok(ContractEvent::RoleGranted(source.get()?)),
+            ROLE_REVOKED_EVENT_TAG => Ok(ContractEvent::RoleRevoked(source.get()?)),
+            ADMIN_PROPOSED_EVENT_TAG => Ok(ContractEvent::AdminProposed(source.get()?)),
+            ADMIN_TRANSFERRED_EVENT_TAG => Ok(ContractEvent::AdminTransferred(source.get()?)),
+            PAUSED_EVENT_TAG => Ok(ContractEvent::Paused(source.get()?)),
+            UNPAUSED_EVENT_TAG => Ok(ContractEvent::Unpaused(source.get()?)),
+            METADATA_FROZEN_EVENT_TAG => Ok(ContractEvent::MetadataFrozen(source.get()?)),
+            MATURITY_AMENDED_EVENT_TAG => Ok(ContractEvent::MaturityAmended(source.get()?)),
+            _ => Err(ParseError::default()),
         }
     }
 }
@@ -118,6 +288,17 @@ impl SchemaType for ContractEvent {
                 ]),
             ),
         );
+        event_map.insert(
+            UPDATE_OPERATOR_EVENT_TAG,
+            (
+                "UpdateOperator".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("update"), OperatorUpdate::get_type()),
+                    (String::from("owner"), Address::get_type()),
+                    (String::from("operator"), Address::get_type()),
+                ]),
+            ),
+        );
         event_map.insert(
             RETIRE_EVENT_TAG,
             (
@@ -157,7 +338,7 @@ impl SchemaType for ContractEvent {
                 "MaturityTime".to_string(),
                 schema::Fields::Named(vec![
                     (String::from("token_id"), ContractTokenId::get_type()),
-                    (String::from("maturity_time"), Timestamp::get_type()),
+                    (String::from("maturity"), MaturitySchedule::get_type()),
                 ]),
             ),
         );
@@ -195,6 +376,131 @@ impl SchemaType for ContractEvent {
                 ]),
             ),
         );
+        event_map.insert(
+            RETIREMENT_CERTIFICATE_EVENT_TAG,
+            (
+                "RetirementCertificate".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("certificate_id"), CertificateId::get_type()),
+                    (String::from("token_id"), ContractTokenId::get_type()),
+                    (String::from("amount"), ContractTokenAmount::get_type()),
+                    (String::from("owner"), Address::get_type()),
+                    (String::from("retired_at"), Timestamp::get_type()),
+                    (String::from("details"), RetirementDetails::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            QUORUM_UPDATED_EVENT_TAG,
+            (
+                "QuorumUpdated".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("threshold"), u32::get_type()),
+                    (String::from("weights"), Vec::<VerifierWeight>::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            VERIFICATION_REVOKED_EVENT_TAG,
+            (
+                "VerificationRevoked".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("token_id"), ContractTokenId::get_type()),
+                    (String::from("verifier"), Address::get_type()),
+                    (String::from("revoked_by"), Address::get_type()),
+                    (String::from("reason"), RevocationReason::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            VERIFICATIONS_AFFECTED_EVENT_TAG,
+            (
+                "VerificationsAffected".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("verifier"), Address::get_type()),
+                    (
+                        String::from("token_ids"),
+                        Vec::<ContractTokenId>::get_type(),
+                    ),
+                    (String::from("invalidated"), bool::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ROLE_GRANTED_EVENT_TAG,
+            (
+                "RoleGranted".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("address"), Address::get_type()),
+                    (String::from("role"), Role::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ROLE_REVOKED_EVENT_TAG,
+            (
+                "RoleRevoked".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("address"), Address::get_type()),
+                    (String::from("role"), Role::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ADMIN_PROPOSED_EVENT_TAG,
+            (
+                "AdminProposed".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("admin"), Address::get_type()),
+                    (String::from("proposed_admin"), Address::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            ADMIN_TRANSFERRED_EVENT_TAG,
+            (
+                "AdminTransferred".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("previous_admin"), Address::get_type()),
+                    (String::from("admin"), Address::get_type()),
+                ]),
+            ),
+        );
+        event_map.insert(
+            PAUSED_EVENT_TAG,
+            (
+                "Paused".to_string(),
+                schema::Fields::Named(vec![(String::from("pauser"), Address::get_type())]),
+            ),
+        );
+        event_map.insert(
+            UNPAUSED_EVENT_TAG,
+            (
+                "Unpaused".to_string(),
+                schema::Fields::Named(vec![(String::from("pauser"), Address::get_type())]),
+            ),
+        );
+        event_map.insert(
+            METADATA_FROZEN_EVENT_TAG,
+            (
+                "MetadataFrozen".to_string(),
+                schema::Fields::Named(vec![(
+                    String::from("token_id"),
+                    ContractTokenId::get_type(),
+                )]),
+            ),
+        );
+        event_map.insert(
+            MATURITY_AMENDED_EVENT_TAG,
+            (
+                "MaturityAmended".to_string(),
+                schema::Fields::Named(vec![
+                    (String::from("token_id"), ContractTokenId::get_type()),
+                    (String::from("verifier"), Address::get_type()),
+                    (String::from("reason"), String::get_type()),
+                ]),
+            ),
+        );
         schema::Type::TaggedEnum(event_map)
     }
 }
@@ -2,16 +2,30 @@ use concordium_std::*;
 
 use super::state::State;
 
+#[derive(Serial, Deserial, SchemaType)]
+pub struct InitParams {
+    /// Whether the metadata url of a token must have a hash.
+    pub metadata_hash_required: bool,
+}
+
 /// Initialize contract instance with a no token types.
 #[init(
     contract = "project_token",
     event = "super::events::ContractEvent",
-    error = "super::error::ContractError"
+    error = "super::error::ContractError",
+    parameter = "InitParams"
 )]
 pub fn init<S: HasStateApi>(
-    _ctx: &impl HasInitContext,
+    ctx: &impl HasInitContext,
     state_builder: &mut StateBuilder<S>,
 ) -> InitResult<State<S>> {
-    // Construct the initial contract state.
-    Ok(State::empty(state_builder))
+    // Parse the parameter.
+    let params: InitParams = ctx.parameter_cursor().get()?;
+    // Construct the initial contract state. The account creating the contract
+    // is its admin.
+    Ok(State::empty(
+        state_builder,
+        Address::Account(ctx.init_origin()),
+        params.metadata_hash_required,
+    ))
 }
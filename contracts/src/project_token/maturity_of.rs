@@ -5,7 +5,7 @@ use crate::{
     project_token::{contract_types::*, state::*},
 };
 
-/// Returns the maturity time of the given tokens.
+/// Returns the maturity schedule of the given tokens.
 #[receive(
     contract = "project_token",
     name = "maturityOf",
@@ -25,7 +25,7 @@ pub fn maturity_of<S: HasStateApi>(
         .map(|token_id| {
             let state = host.state();
             let token = state.get_token(token_id).unwrap();
-            Ok(token.maturity_time)
+            Ok(token.maturity)
         })
         .collect()
 }
@@ -1,13 +1,15 @@
 use crate::{
-    client_utils::types::ContractMetadataUrl,
-    project_token::{contract_types::*, events::*, state::*},
+    client_utils::roles::Role,
+    client_utils::types::{ContractMetadataUrl, MaturitySchedule, ProjectInfo},
+    project_token::{contract_types::*, error::*, events::*, state::*},
 };
 use concordium_std::*;
 
 #[derive(Serial, Deserial, SchemaType)]
 pub struct MintParam {
     pub metadata_url: ContractMetadataUrl,
-    pub maturity_time: Timestamp,
+    pub maturity: MaturitySchedule,
+    pub project_info: ProjectInfo,
 }
 
 /// The parameter for the contract function `mint` which mints a number of
@@ -24,20 +26,25 @@ pub struct MintParams {
 pub struct MintResponse(Vec<ContractTokenId>);
 
 /// Mint new tokens with a given address as the owner of these tokens.
-/// Can only be called by the contract owner.
-/// Logs a `Mint` and a `TokenMetadata` event for each token.
+/// Can only be called by an address with the `Minter` role.
+/// Logs a `Mint`, a `TokenMetadata` and a `MaturityTime` event for each token.
 /// The url for the token metadata is the token ID encoded in hex, appended on
 /// the `TOKEN_METADATA_BASE_URL`.
 ///
 /// It rejects if:
-/// - The sender is not the contract instance owner.
+/// - The sender does not have the `Minter` role.
 /// - Fails to parse parameter.
 /// - Any of the tokens fails to be minted, which could be if:
+///     - The hash of its metadata url is malformed, or missing while hashes
+///       are required.
+///     - Its maturity schedule is not ordered by time or does not add up to
+///       100%.
 ///     - Fails to log Mint event.
 ///     - Fails to log TokenMetadata event.
+///     - Fails to log MaturityTime event.
 ///
-/// Note: Can at most mint 32 token types in one call due to the limit on the
-/// number of logs a smart contract can produce on each function call.
+/// Note: Can at most mint 21 token types in one call due to the limit of 64
+/// logs a smart contract can produce on each function call.
 #[receive(
     contract = "project_token",
     name = "mint",
@@ -52,18 +59,29 @@ fn mint<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<MintResponse> {
-    // Mint function is unauthorized
-    // Anyone having an account on the chain can call this function
-    // Represents that anyone can create a Carbon Credit Project
-
+    // Ensure that the contract is not paused.
+    ensure!(
+        !host.state().paused,
+        ContractError::Custom(CustomContractError::Paused)
+    );
     // Parse the parameter.
     let params: MintParams = ctx.parameter_cursor().get()?;
 
     let mut res: Vec<ContractTokenId> = Vec::new();
     let (state, builder) = host.state_and_builder();
+    // Only minters can create a Carbon Credit Project
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Minter),
+        ContractError::Unauthorized
+    );
     for mint_param in params.tokens {
+        ensure!(
+            mint_param.maturity.is_valid(),
+            ContractError::Custom(CustomContractError::InvalidMaturitySchedule)
+        );
         // Mint the token in the state.
-        let token_id = state.mint(&mint_param, &params.owner, builder);
+        let metadata_url = state.metadata_url(mint_param.metadata_url.clone())?;
+        let token_id = state.mint(&mint_param, metadata_url.clone(), &params.owner, builder)?;
 
         // Event for minted token.
         logger.log(&ContractEvent::Mint(super::events::MintEvent {
@@ -75,14 +93,14 @@ fn mint<S: HasStateApi>(
         logger.log(&ContractEvent::TokenMetadata(
             super::events::TokenMetadataEvent {
                 token_id,
-                metadata_url: mint_param.metadata_url.into(),
+                metadata_url,
             },
         ))?;
-        // Event for maturity time.
+        // Event for maturity schedule.
         logger.log(&ContractEvent::MaturityTime(
             super::events::MaturityTimeEvent {
                 token_id,
-                maturity_time: mint_param.maturity_time,
+                maturity: mint_param.maturity,
             },
         ))?;
 
@@ -91,3 +109,25 @@ fn mint<S: HasStateApi>(
 
     Ok(MintResponse(res))
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::project_token::state::test_utils::*;
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_not_mint_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::default();
+        let result = mint(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(
+            result.err(),
+            Some(ContractError::Custom(CustomContractError::Paused))
+        );
+    }
+}
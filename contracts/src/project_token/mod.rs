@@ -6,11 +6,19 @@ pub mod events;
 // Contract functions
 pub mod init;
 pub mod mint;
+pub mod update_metadata;
 pub mod retire;
+pub mod retirement_of;
 pub mod maturity_of;
+pub mod project_info;
 pub mod retract;
 pub mod verifier_operations;
+pub mod role_operations;
+pub mod pause;
+pub mod upgrade;
 pub mod is_verified;
+pub mod verification_status;
+pub mod verifications_of;
 
 // CIS2 functions
 pub mod balance_of;
@@ -17,8 +17,17 @@ use super::{contract_types::*, state::*};
 )]
 pub fn operator_of<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    _host: &impl HasHost<State<S>, StateApiType = S>,
+    host: &impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<OperatorOfQueryResponse> {
+    // Parse the parameter.
     let params: OperatorOfQueryParams = ctx.parameter_cursor().get()?;
-    Ok(OperatorOfQueryResponse(vec![false; params.queries.len()]))
+    // Build the response.
+    let mut response = Vec::with_capacity(params.queries.len());
+    for query in params.queries {
+        // Query the state for address being an operator of owner.
+        let is_operator = host.state().is_operator(&query.address, &query.owner);
+        response.push(is_operator);
+    }
+    let result = OperatorOfQueryResponse::from(response);
+    Ok(result)
 }
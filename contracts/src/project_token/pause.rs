@@ -0,0 +1,90 @@
+use concordium_std::*;
+
+use crate::client_utils::{
+    roles::Role,
+    types::{PauseEvent, SetPausedParams},
+};
+
+use super::{contract_types::ContractResult, error::*, events::*, state::State};
+
+/// Pauses or unpauses the transfers, mints, retirements and retractions of
+/// the contract. Can only be called by a pauser.
+#[receive(
+    contract = "project_token",
+    name = "setPaused",
+    parameter = "SetPausedParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn set_paused<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Parse the parameter.
+    let SetPausedParams { paused } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Authenticate the sender
+    let pauser = ctx.sender();
+    ensure!(
+        state.roles.has_role(&pauser, Role::Pauser),
+        ContractError::Unauthorized
+    );
+    // Update the contract state
+    state.paused = paused;
+    let event = PauseEvent { pauser };
+    logger.log(&if paused {
+        ContractEvent::Paused(event)
+    } else {
+        ContractEvent::Unpaused(event)
+    })?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::project_token::state::test_utils::*;
+    use concordium_std::test_infrastructure::*;
+
+    const PAUSER: Address = Address::Account(AccountAddress([2u8; 32]));
+
+    #[concordium_test]
+    fn should_pause_and_unpause() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder);
+        state.roles.grant(&PAUSER, Role::Pauser, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&SetPausedParams { paused: true });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        // Only a pauser can pause, not even the admin.
+        ctx.set_sender(ADMIN);
+        let result = set_paused(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert!(!host.state().paused);
+
+        ctx.set_sender(PAUSER);
+        let result = set_paused(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert!(host.state().paused);
+
+        let parameter_bytes = to_bytes(&SetPausedParams { paused: false });
+        ctx.set_parameter(&parameter_bytes);
+        let result = set_paused(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert!(!host.state().paused);
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::Paused(PauseEvent { pauser: PAUSER })),
+                to_bytes(&ContractEvent::Unpaused(PauseEvent { pauser: PAUSER })),
+            ]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use concordium_std::*;
+
+use crate::{
+    client_utils::types::ProjectInfoQueryResponse,
+    project_token::{contract_types::*, error::*, state::*},
+};
+
+/// Returns the registry information of the given tokens.
+#[receive(
+    contract = "project_token",
+    name = "projectInfo",
+    parameter = "ContractProjectInfoQueryParams",
+    return_value = "ProjectInfoQueryResponse",
+    error = "ContractError"
+)]
+pub fn project_info<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ProjectInfoQueryResponse> {
+    // Parse the parameter.
+    let params: ContractProjectInfoQueryParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    params
+        .queries
+        .iter()
+        .map(|token_id| {
+            let token = state
+                .get_token(token_id)
+                .ok_or(ContractError::InvalidTokenId)?;
+            Ok(token.project_info)
+        })
+        .collect()
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::project_token::state::test_utils::*;
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_query_project_info() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder);
+        let host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&ContractProjectInfoQueryParams {
+            queries: vec![token_id],
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        let result = project_info(&ctx, &host);
+        assert_eq!(result, Ok(vec![mint_param().project_info]));
+
+        let parameter_bytes = to_bytes(&ContractProjectInfoQueryParams {
+            queries: vec![token_id, ContractTokenId::from(1)],
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = project_info(&ctx, &host);
+        assert_eq!(result, Err(ContractError::InvalidTokenId));
+    }
+}
@@ -1,11 +1,13 @@
 use concordium_std::*;
 
+use crate::client_utils::types::{Beneficiary, RetirementDetails};
+
 use super::{contract_types::*, error::*, events::*, state::*};
 
 #[receive(
     contract = "project_token",
     name = "retire",
-    parameter = "ContractBurnParams",
+    parameter = "ContractRetireParams",
     error = "ContractError",
     enable_logger,
     mutable
@@ -15,9 +17,70 @@ fn retire<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
-    let ContractBurnParams { owner, tokens } = ctx.parameter_cursor().get()?;
+    let ContractRetireParams {
+        owner,
+        tokens,
+        details,
+    } = ctx.parameter_cursor().get()?;
     ensure!(ctx.sender() == owner, ContractError::Unauthorized);
 
+    retire_tokens(ctx, host, logger, owner, tokens, details)
+}
+
+/// Retires the tokens of the owner with the retirement attributed to the
+/// beneficiary. Can be called by the owner or an operator of the owner.
+#[receive(
+    contract = "project_token",
+    name = "retireFor",
+    parameter = "ContractRetireForParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+fn retire_for<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let ContractRetireForParams {
+        owner,
+        tokens,
+        beneficiary,
+        reason,
+        reporting_period,
+    } = ctx.parameter_cursor().get()?;
+    let sender = ctx.sender();
+    ensure!(
+        sender == owner || host.state().is_operator(&sender, &owner),
+        ContractError::Unauthorized
+    );
+
+    let details = RetirementDetails {
+        beneficiary: Beneficiary::Address(beneficiary),
+        reason,
+        reporting_period,
+    };
+    retire_tokens(ctx, host, logger, owner, tokens, details)
+}
+
+fn retire_tokens<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+    owner: Address,
+    tokens: Vec<ContractBurnParam>,
+    details: RetirementDetails,
+) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(
+        !host.state().paused,
+        ContractError::Custom(CustomContractError::Paused)
+    );
+    ensure!(
+        details.reporting_period.start <= details.reporting_period.end,
+        ContractError::Custom(CustomContractError::InvalidReportingPeriod)
+    );
+
     let state = host.state_mut();
     for ContractBurnParam { token_id, amount } in tokens {
         ensure!(amount == 1.into(), ContractError::InsufficientFunds);
@@ -46,6 +109,13 @@ fn retire<S: HasStateApi>(
 
         // Retire token.
         state.burn(&token_id, &owner)?;
+        let certificate = state.add_retirement(
+            token_id,
+            amount,
+            owner,
+            ctx.metadata().slot_time(),
+            details.clone(),
+        );
 
         //log token retire event.
         logger.log(&ContractEvent::Retire(BurnEvent {
@@ -60,7 +130,214 @@ fn retire<S: HasStateApi>(
             owner,
             amount,
         }))?;
+
+        // log retirement certificate event
+        logger.log(&ContractEvent::RetirementCertificate(certificate))?;
     }
 
     Ok(())
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{client_utils::types::ReportingPeriod, project_token::state::test_utils::*};
+    use concordium_std::test_infrastructure::*;
+
+    const RETIRED_AT: u64 = 1000;
+
+    fn details() -> RetirementDetails {
+        RetirementDetails {
+            beneficiary: Beneficiary::Name("ACME Ltd".to_string()),
+            reason: "Offsetting 2023 business travel".to_string(),
+            reporting_period: ReportingPeriod {
+                start: Timestamp::from_timestamp_millis(1),
+                end: Timestamp::from_timestamp_millis(2),
+            },
+        }
+    }
+
+    #[concordium_test]
+    fn should_retire_with_certificates() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_0) = state_with_verified_token(&mut state_builder);
+        let metadata_url = state
+            .metadata_url(mint_param().metadata_url)
+            .expect_report("Valid metadata url");
+        let token_1 = state
+            .mint(&mint_param(), metadata_url, &OWNER, &mut state_builder)
+            .expect_report("Token is minted");
+        for verifier in [VERIFIER_0, VERIFIER_1] {
+            state.verify_token(&token_1, &verifier, attestation(), &mut state_builder);
+        }
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&ContractRetireParams {
+            owner: OWNER,
+            tokens: vec![
+                ContractBurnParam {
+                    token_id: token_0,
+                    amount: 1.into(),
+                },
+                ContractBurnParam {
+                    token_id: token_1,
+                    amount: 1.into(),
+                },
+            ],
+            details: details(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(RETIRED_AT));
+
+        let result = retire(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+
+        // Every retirement gets the next certificate id.
+        let certificate = |certificate_id, token_id| ContractRetirementCertificate {
+            certificate_id,
+            token_id,
+            amount: 1.into(),
+            owner: OWNER,
+            retired_at: Timestamp::from_timestamp_millis(RETIRED_AT),
+            details: details(),
+        };
+        assert_eq!(host.state().next_certificate_id, 2);
+        assert_eq!(
+            host.state().get_retirement(&0),
+            Some(certificate(0, token_0))
+        );
+        assert_eq!(
+            host.state().get_retirement(&1),
+            Some(certificate(1, token_1))
+        );
+        assert_eq!(host.state().balance(&token_0, &OWNER), Ok(0.into()));
+        assert_eq!(host.state().balance(&token_1, &OWNER), Ok(0.into()));
+
+        let retire_events = |certificate_id, token_id| {
+            let event = || BurnEvent {
+                token_id,
+                amount: 1.into(),
+                owner: OWNER,
+            };
+            vec![
+                to_bytes(&ContractEvent::Retire(event())),
+                to_bytes(&ContractEvent::Burn(event())),
+                to_bytes(&ContractEvent::RetirementCertificate(certificate(
+                    certificate_id,
+                    token_id,
+                ))),
+            ]
+        };
+        assert_eq!(
+            logger.logs,
+            [retire_events(0, token_0), retire_events(1, token_1)].concat()
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_retire_tokens_of_others() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_verified_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&ContractRetireParams {
+            owner: OWNER,
+            tokens: vec![ContractBurnParam {
+                token_id,
+                amount: 1.into(),
+            }],
+            details: details(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(ADMIN);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(RETIRED_AT));
+
+        let result = retire(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(host.state().next_certificate_id, 0);
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(1.into()));
+    }
+
+    #[concordium_test]
+    fn should_retire_for_beneficiary_by_operator() {
+        let operator = Address::Account(AccountAddress([2u8; 32]));
+        let beneficiary = Address::Account(AccountAddress([3u8; 32]));
+
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_verified_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&ContractRetireForParams {
+            owner: OWNER,
+            tokens: vec![ContractBurnParam {
+                token_id,
+                amount: 1.into(),
+            }],
+            beneficiary,
+            reason: details().reason,
+            reporting_period: details().reporting_period,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(operator);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(RETIRED_AT));
+
+        // The sender is not an operator of the owner.
+        let result = retire_for(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(1.into()));
+
+        let (state, builder) = host.state_and_builder();
+        state.add_operator(&OWNER, &operator, builder);
+        let result = retire_for(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(0.into()));
+        // The certificate is issued to the owner on behalf of the beneficiary.
+        assert_eq!(
+            host.state().get_retirement(&0),
+            Some(ContractRetirementCertificate {
+                certificate_id: 0,
+                token_id,
+                amount: 1.into(),
+                owner: OWNER,
+                retired_at: Timestamp::from_timestamp_millis(RETIRED_AT),
+                details: RetirementDetails {
+                    beneficiary: Beneficiary::Address(beneficiary),
+                    ..details()
+                },
+            })
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_retire_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_verified_token(&mut state_builder);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&ContractRetireParams {
+            owner: OWNER,
+            tokens: vec![ContractBurnParam {
+                token_id,
+                amount: 1.into(),
+            }],
+            details: details(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+
+        let result = retire(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(CustomContractError::Paused))
+        );
+    }
+}
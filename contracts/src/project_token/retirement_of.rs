@@ -0,0 +1,85 @@
+use concordium_std::*;
+
+use crate::{
+    client_utils::types::RetirementOfQueryParams,
+    project_token::{contract_types::*, error::*, state::*},
+};
+
+/// Returns the certificates of the given retirements.
+#[receive(
+    contract = "project_token",
+    name = "retirementOf",
+    parameter = "RetirementOfQueryParams",
+    return_value = "ContractRetirementOfQueryResponse",
+    error = "ContractError"
+)]
+pub fn retirement_of<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ContractRetirementOfQueryResponse> {
+    // Parse the parameter.
+    let params: RetirementOfQueryParams = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    params
+        .queries
+        .iter()
+        .map(|certificate_id| {
+            state
+                .get_retirement(certificate_id)
+                .ok_or(ContractError::Custom(
+                    CustomContractError::InvalidCertificateId,
+                ))
+        })
+        .collect()
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        client_utils::types::{Beneficiary, ReportingPeriod, RetirementDetails},
+        project_token::state::test_utils::*,
+    };
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_query_retirements() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder);
+        let certificate = state.add_retirement(
+            token_id,
+            1.into(),
+            OWNER,
+            Timestamp::from_timestamp_millis(1000),
+            RetirementDetails {
+                beneficiary: Beneficiary::Address(OWNER),
+                reason: "Offsetting 2023 business travel".to_string(),
+                reporting_period: ReportingPeriod {
+                    start: Timestamp::from_timestamp_millis(1),
+                    end: Timestamp::from_timestamp_millis(2),
+                },
+            },
+        );
+        let host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&RetirementOfQueryParams { queries: vec![0] });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        let result = retirement_of(&ctx, &host);
+        assert_eq!(result, Ok(vec![certificate]));
+
+        // Queries of unknown certificates fail.
+        let parameter_bytes = to_bytes(&RetirementOfQueryParams {
+            queries: vec![0, 1],
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = retirement_of(&ctx, &host);
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(
+                CustomContractError::InvalidCertificateId
+            ))
+        );
+    }
+}
@@ -17,6 +17,11 @@ fn retract<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(
+        !host.state().paused,
+        ContractError::Custom(CustomContractError::Paused)
+    );
     let ContractBurnParams { owner, tokens } = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
     let sender = ctx.sender();
@@ -66,3 +71,22 @@ fn retract<S: HasStateApi>(
 
     Ok(())
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::project_token::state::test_utils::*;
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_not_retract_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::default();
+        let result = retract(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(result, Err(ContractError::Custom(CustomContractError::Paused)));
+    }
+}
@@ -0,0 +1,262 @@
+use concordium_std::*;
+
+use crate::client_utils::roles::{
+    AdminProposedEvent, AdminTransferredEvent, ProposeAdminParams, Role, RoleParams,
+    RoleUpdatedEvent,
+};
+
+use super::{contract_types::ContractResult, error::*, events::*, state::State};
+
+/// Grants a role to an address. Can only be called by an admin.
+#[receive(
+    contract = "project_token",
+    name = "grantRole",
+    parameter = "RoleParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn grant_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Parse the parameter.
+    let RoleParams { address, role } = ctx.parameter_cursor().get()?;
+    let (state, builder) = host.state_and_builder();
+    // Authenticate the sender
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        ContractError::Unauthorized
+    );
+    // Update the contract state
+    state.roles.grant(&address, role, builder);
+    logger.log(&ContractEvent::RoleGranted(RoleUpdatedEvent {
+        address,
+        role,
+    }))?;
+
+    Ok(())
+}
+
+/// Revokes a role from an address. Can only be called by an admin.
+/// The `Verifier` role is revoked with `removeVerifier`, which also decides
+/// on the verifications of the verifier.
+#[receive(
+    contract = "project_token",
+    name = "revokeRole",
+    parameter = "RoleParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn revoke_role<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Parse the parameter.
+    let RoleParams { address, role } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Authenticate the sender
+    ensure!(
+        state.roles.has_role(&ctx.sender(), Role::Admin),
+        ContractError::Unauthorized
+    );
+    ensure!(
+        role != Role::Verifier,
+        ContractError::Custom(CustomContractError::InvalidRole)
+    );
+    // Update the contract state
+    state.roles.revoke(&address, role);
+    logger.log(&ContractEvent::RoleRevoked(RoleUpdatedEvent {
+        address,
+        role,
+    }))?;
+
+    Ok(())
+}
+
+/// Proposes a new admin of the contract. The proposed admin becomes the admin
+/// once it calls `acceptAdmin`. Can only be called by the admin.
+#[receive(
+    contract = "project_token",
+    name = "proposeAdmin",
+    parameter = "ProposeAdminParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn propose_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Parse the parameter.
+    let ProposeAdminParams {
+        admin: proposed_admin,
+    } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Authenticate the sender
+    let admin = state.roles.admin();
+    ensure!(ctx.sender() == admin, ContractError::Unauthorized);
+    // Update the contract state
+    state.roles.propose_admin(proposed_admin);
+    logger.log(&ContractEvent::AdminProposed(AdminProposedEvent {
+        admin,
+        proposed_admin,
+    }))?;
+
+    Ok(())
+}
+
+/// Makes the sender the admin of the contract. Can only be called by the
+/// admin proposed with `proposeAdmin`.
+#[receive(
+    contract = "project_token",
+    name = "acceptAdmin",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn accept_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let admin = ctx.sender();
+    let previous_admin = host
+        .state_mut()
+        .roles
+        .accept_admin(&admin)
+        .ok_or(ContractError::Unauthorized)?;
+    logger.log(&ContractEvent::AdminTransferred(AdminTransferredEvent {
+        previous_admin,
+        admin,
+    }))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::project_token::state::test_utils::*;
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_grant_and_revoke_roles() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, _) = state_with_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&RoleParams {
+            address: OWNER,
+            role: Role::Minter,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        // Only an admin can grant roles.
+        ctx.set_sender(OWNER);
+        let mut logger = TestLogger::init();
+        let result = grant_role(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        ctx.set_sender(ADMIN);
+        let result = grant_role(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert!(host.state().roles.has_role(&OWNER, Role::Minter));
+
+        // Only an admin can revoke roles.
+        ctx.set_sender(OWNER);
+        let result = revoke_role(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        ctx.set_sender(ADMIN);
+        let result = revoke_role(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert!(!host.state().roles.has_role(&OWNER, Role::Minter));
+        let event = || RoleUpdatedEvent {
+            address: OWNER,
+            role: Role::Minter,
+        };
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::RoleGranted(event())),
+                to_bytes(&ContractEvent::RoleRevoked(event())),
+            ]
+        );
+    }
+
+    #[concordium_test]
+    fn should_transfer_admin() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, _) = state_with_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let propose = |host: &mut TestHost<State<TestStateApi>>, sender, admin| {
+            let parameter_bytes = to_bytes(&ProposeAdminParams { admin });
+            let mut ctx = TestReceiveContext::default();
+            ctx.set_sender(sender);
+            ctx.set_parameter(&parameter_bytes);
+            propose_admin(&ctx, host, &mut TestLogger::init())
+        };
+
+        // Only the admin can propose an admin.
+        assert_eq!(
+            propose(&mut host, OWNER, OWNER),
+            Err(ContractError::Unauthorized)
+        );
+        assert_eq!(propose(&mut host, ADMIN, OWNER), Ok(()));
+        assert_eq!(host.state().roles.proposed_admin(), Some(OWNER));
+
+        // Only the proposed admin can accept.
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADMIN);
+        let result = accept_admin(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        ctx.set_sender(OWNER);
+        let result = accept_admin(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().roles.admin(), OWNER);
+        assert!(!host.state().roles.has_role(&ADMIN, Role::Admin));
+        assert_eq!(
+            logger.logs,
+            vec![to_bytes(&ContractEvent::AdminTransferred(
+                AdminTransferredEvent {
+                    previous_admin: ADMIN,
+                    admin: OWNER,
+                }
+            ))]
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_revoke_verifier_role() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_verified_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&RoleParams {
+            address: VERIFIER_0,
+            role: Role::Verifier,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(ADMIN);
+
+        let result = revoke_role(&ctx, &mut host, &mut logger);
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(CustomContractError::InvalidRole))
+        );
+        assert!(host.state().is_verifier(&VERIFIER_0));
+        assert!(host.state().is_verified_by(&token_id, &VERIFIER_0));
+        assert!(logger.logs.is_empty());
+    }
+}
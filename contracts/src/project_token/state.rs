@@ -1,7 +1,15 @@
 use concordium_cis2::*;
 use concordium_std::*;
 
-use super::{contract_types::*, error::*, mint::MintParam};
+use super::{contract_types::*, error::*, events::VerifierWeight, mint::MintParam};
+use crate::client_utils::{
+    roles::{Role, Roles},
+    types::{
+        Attestation, CertificateId, ContractMetadataUrl, MaturitySchedule, ProjectInfo,
+        RetirementDetails, Verification, VerificationStatus,
+    },
+    upgrade::StateVersion,
+};
 
 /// The state for each address.
 #[derive(Serial, DeserialWithState, Deletable, StateClone)]
@@ -14,21 +22,16 @@ pub struct AddressState<S> {
 #[derive(Serial, Deserial, Clone)]
 pub struct ContractTokenMetadata {
     pub metadata_url: MetadataUrl,
-    pub maturity_time: Timestamp,
+    pub maturity: MaturitySchedule,
+    pub project_info: ProjectInfo,
+    /// Whether the metadata url can no longer be updated. Set by the first
+    /// verification of the token and never unset.
+    pub metadata_frozen: bool,
 }
 
 impl ContractTokenMetadata {
     pub fn is_mature(&self, now: &Timestamp) -> bool {
-        self.maturity_time.cmp(now).is_le()
-    }
-}
-
-impl From<&MintParam> for ContractTokenMetadata {
-    fn from(mint_param: &MintParam) -> Self {
-        ContractTokenMetadata {
-            metadata_url: mint_param.metadata_url.clone().into(),
-            maturity_time: mint_param.maturity_time,
-        }
+        self.maturity.is_mature(now)
     }
 }
 
@@ -40,6 +43,10 @@ impl<S: HasStateApi> AddressState<S> {
     }
 }
 
+/// Version of the layout of [`State`]. See [`crate::client_utils::upgrade`]
+/// for when it is increased.
+pub const STATE_VERSION: StateVersion = 1;
+
 /// The contract state,
 ///
 /// Note: The specification does not specify how to structure the contract state
@@ -47,90 +54,290 @@ impl<S: HasStateApi> AddressState<S> {
 #[derive(Serial, DeserialWithState, StateClone)]
 #[concordium(state_parameter = "S")]
 pub struct State<S> {
+    /// Version of the layout of the state. Always the first field.
+    pub version: StateVersion,
     /// The state of addresses.
     pub balances: StateMap<Address, StateSet<ContractTokenId, S>, S>,
     /// All of the token IDs
     pub metadatas: StateMap<ContractTokenId, ContractTokenMetadata, S>,
     pub last_token_id: ContractTokenId,
-    /// Set of Verifiers
-    pub verifiers: StateSet<Address, S>,
-    pub verified_tokens: StateMap<ContractTokenId, StateSet<Address, S>, S>,
+    /// Roles of the addresses, including the verifiers.
+    pub roles: Roles<S>,
+    /// Weights of the verifiers. A verifier without a weight has a weight of 1.
+    pub verifier_weights: StateMap<Address, u32, S>,
+    /// Total weight of the verifiers needed for a token to be verified.
+    pub quorum: u32,
+    /// Attestations of the verifiers of each token.
+    pub verified_tokens: StateMap<ContractTokenId, StateMap<Address, Attestation, S>, S>,
+    /// Tokens verified by each verifier. The reverse of `verified_tokens`.
+    pub verifier_tokens: StateMap<Address, StateSet<ContractTokenId, S>, S>,
+    /// Operators of each owner address.
+    pub operators: StateMap<Address, StateSet<Address, S>, S>,
+    /// Retirement certificates by their ID.
+    pub retirements: StateMap<CertificateId, ContractRetirementCertificate, S>,
+    /// ID of the next retirement certificate.
+    pub next_certificate_id: CertificateId,
+    /// Whether the transfers, mints, retirements and retractions are paused.
+    pub paused: bool,
+    /// Whether the metadata url of a token must have a hash.
+    pub metadata_hash_required: bool,
 }
 
 impl<S: HasStateApi> State<S> {
     /// Construct a state with no tokens
-    pub fn empty(state_builder: &mut StateBuilder<S>) -> Self {
+    pub fn empty(
+        state_builder: &mut StateBuilder<S>,
+        admin: Address,
+        metadata_hash_required: bool,
+    ) -> Self {
         State {
+            version: STATE_VERSION,
             balances: state_builder.new_map(),
             metadatas: state_builder.new_map(),
             last_token_id: 0.into(),
-            verifiers: state_builder.new_set(),
+            roles: Roles::new(admin, state_builder),
+            verifier_weights: state_builder.new_map(),
+            quorum: 1,
             verified_tokens: state_builder.new_map(),
+            verifier_tokens: state_builder.new_map(),
+            operators: state_builder.new_map(),
+            retirements: state_builder.new_map(),
+            next_certificate_id: 0,
+            paused: false,
+            metadata_hash_required,
         }
     }
 
     /// Adds a verifier to the contract state.
-    pub fn add_verifier(&mut self, verifier: &Address) {
-        self.verifiers.insert(*verifier);
+    pub fn add_verifier(&mut self, verifier: &Address, state_builder: &mut StateBuilder<S>) {
+        self.roles.grant(verifier, Role::Verifier, state_builder);
     }
 
     /// Removes a verifier from the contract state.
     pub fn remove_verifier(&mut self, verifier: &Address) {
-        self.verifiers.remove(verifier);
+        self.roles.revoke(verifier, Role::Verifier);
     }
 
     /// Checks if a given address is a verifier.
     pub fn is_verifier(&self, verifier: &Address) -> bool {
-        self.verifiers.contains(verifier)
+        self.roles.has_role(verifier, Role::Verifier)
     }
 
-    /// Checks if a given token is verified
+    /// Checks if a given address is an admin.
+    pub fn is_admin(&self, address: &Address) -> bool {
+        self.roles.has_role(address, Role::Admin)
+    }
+
+    /// Weight of the verifications of a verifier.
+    pub fn verifier_weight(&self, verifier: &Address) -> u32 {
+        self.verifier_weights
+            .get(verifier)
+            .map_or(1, |weight| *weight)
+    }
+
+    /// Sets the quorum and updates the weights of the given verifiers.
+    pub fn set_quorum(&mut self, threshold: u32, weights: &[VerifierWeight]) {
+        self.quorum = threshold;
+        for VerifierWeight { verifier, weight } in weights {
+            self.verifier_weights.insert(*verifier, *weight);
+        }
+    }
+
+    /// Gets the verification status of a token from the total weight of the
+    /// verifiers which verified it.
+    pub fn verification_status(&self, token_id: &ContractTokenId) -> VerificationStatus {
+        let verifiers = match self.verified_tokens.get(token_id) {
+            Some(verifiers) => verifiers,
+            None => return VerificationStatus::Unverified,
+        };
+        let weight: u64 = verifiers
+            .iter()
+            .map(|(verifier, _)| u64::from(self.verifier_weight(&verifier)))
+            .sum();
+
+        if weight >= u64::from(self.quorum) {
+            VerificationStatus::Verified
+        } else {
+            VerificationStatus::Pending
+        }
+    }
+
+    /// Checks if a given token is verified by a quorum of verifiers.
     pub fn is_verified(&self, token_id: &ContractTokenId) -> bool {
-        self.verified_tokens.get(token_id).is_some()
+        self.verification_status(token_id) == VerificationStatus::Verified
     }
 
-    /// Verify a token
+    /// Verify a token, replacing a previous attestation of the verifier.
     pub fn verify_token(
         &mut self,
         token_id: &ContractTokenId,
         verifier: &Address,
+        attestation: Attestation,
         state_builder: &mut StateBuilder<S>,
     ) {
         let mut verified_token = self
             .verified_tokens
             .entry(*token_id)
-            .or_insert_with(|| state_builder.new_set());
-        verified_token.insert(*verifier);
+            .or_insert_with(|| state_builder.new_map());
+        verified_token.insert(*verifier, attestation);
+        self.verifier_tokens
+            .entry(*verifier)
+            .or_insert_with(|| state_builder.new_set())
+            .insert(*token_id);
+    }
+
+    /// Replaces the maturity schedule of a token.
+    /// Results in an error if the token id does not exist in the state.
+    pub fn set_maturity(
+        &mut self,
+        token_id: &ContractTokenId,
+        maturity: MaturitySchedule,
+    ) -> ContractResult<()> {
+        let mut token = self
+            .metadatas
+            .get_mut(token_id)
+            .ok_or(ContractError::InvalidTokenId)?;
+        token.maturity = maturity;
+
+        Ok(())
+    }
+
+    /// Freezes the metadata of a token. Returns false if the metadata was
+    /// already frozen or the token does not exist.
+    pub fn freeze_metadata(&mut self, token_id: &ContractTokenId) -> bool {
+        match self.metadatas.get_mut(token_id) {
+            Some(mut token) if !token.metadata_frozen => {
+                token.metadata_frozen = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes the verification of a token by a verifier. Returns false if the
+    /// verifier had not verified the token.
+    pub fn unverify_token(&mut self, token_id: &ContractTokenId, verifier: &Address) -> bool {
+        let (removed, is_empty) = match self.verified_tokens.get_mut(token_id) {
+            Some(mut verifiers) => (
+                verifiers.remove_and_get(verifier).is_some(),
+                // `StateMap::is_empty` only looks up the map prefix itself, which
+                // is never an entry, so it cannot be used here.
+                verifiers.iter().next().is_none(),
+            ),
+            None => return false,
+        };
+        // A token without verifications is unverified rather than pending.
+        if is_empty {
+            self.verified_tokens.remove(token_id);
+        }
+        if removed {
+            self.remove_verifier_token(verifier, token_id);
+        }
+
+        removed
+    }
+
+    /// Removes a token from the tokens verified by a verifier.
+    fn remove_verifier_token(&mut self, verifier: &Address, token_id: &ContractTokenId) {
+        let is_empty = match self.verifier_tokens.get_mut(verifier) {
+            Some(mut token_ids) => {
+                token_ids.remove(token_id);
+                token_ids.iter().next().is_none()
+            }
+            None => false,
+        };
+        if is_empty {
+            self.verifier_tokens.remove(verifier);
+        }
+    }
+
+    /// Gets the tokens verified by a verifier.
+    pub fn tokens_verified_by(&self, verifier: &Address) -> Vec<ContractTokenId> {
+        self.verifier_tokens
+            .get(verifier)
+            .map(|token_ids| token_ids.iter().map(|token_id| *token_id).collect())
+            .unwrap_or_default()
+    }
+
+    /// Checks if a verifier verified a given token.
+    pub fn is_verified_by(&self, token_id: &ContractTokenId, verifier: &Address) -> bool {
+        self.verified_tokens
+            .get(token_id)
+            .map_or(false, |verifiers| verifiers.get(verifier).is_some())
     }
 
     /// Get the set of verifiers for a given token.
     pub fn get_verifiers(&self, token_id: &ContractTokenId) -> Option<Vec<Address>> {
         self.verified_tokens
             .get(token_id)
-            .map(|x| x.iter().map(|a| *a).collect())
+            .map(|x| x.iter().map(|(a, _)| *a).collect())
+    }
+
+    /// Get the verifications of a given token.
+    pub fn get_verifications(&self, token_id: &ContractTokenId) -> Vec<Verification> {
+        self.verified_tokens
+            .get(token_id)
+            .map(|x| {
+                x.iter()
+                    .map(|(verifier, attestation)| Verification {
+                        verifier: *verifier,
+                        attestation: attestation.to_owned(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Converts the metadata url of a token. Rejects a malformed hash, or a
+    /// missing hash if hashes are required.
+    pub fn metadata_url(&self, metadata_url: ContractMetadataUrl) -> ContractResult<MetadataUrl> {
+        let metadata_url: MetadataUrl = metadata_url.try_into()?;
+        ensure!(
+            !self.metadata_hash_required || metadata_url.hash.is_some(),
+            ContractError::Custom(CustomContractError::MissingMetadataHash)
+        );
+
+        Ok(metadata_url)
     }
 
     /// Mints an amount of tokens with a given address as the owner.
     pub fn mint(
         &mut self,
         mint_param: &MintParam,
+        metadata_url: MetadataUrl,
         owner: &Address,
         state_builder: &mut StateBuilder<S>,
-    ) -> ContractTokenId {
+    ) -> ContractResult<ContractTokenId> {
         let token_id = self.last_token_id;
+        // Allocate the token ID for the next mint.
+        let next_token_id = token_id.0.checked_add(1).ok_or(ContractError::Custom(
+            CustomContractError::TokenIdsExhausted,
+        ))?;
         // Add the token to the contract state.
-        self.metadatas
-            .insert(token_id, ContractTokenMetadata::from(mint_param));
+        self.metadatas.insert(
+            token_id,
+            ContractTokenMetadata {
+                metadata_url,
+                maturity: mint_param.maturity.clone(),
+                project_info: mint_param.project_info.clone(),
+                metadata_frozen: false,
+            },
+        );
         // Add the token to the owner's state.
         self.add(owner, state_builder, token_id);
         // Increment the token ID for the next mint.
-        self.last_token_id = ContractTokenId::from(token_id.0 + 1);
+        self.last_token_id = ContractTokenId::from(next_token_id);
 
         // Return the token ID.
-        token_id
+        Ok(token_id)
     }
 
-    fn add(&mut self, owner: &Address, state_builder: &mut StateBuilder<S>, token_id: TokenIdU8) {
+    fn add(
+        &mut self,
+        owner: &Address,
+        state_builder: &mut StateBuilder<S>,
+        token_id: ContractTokenId,
+    ) {
         let mut owner_state = self
             .balances
             .entry(*owner)
@@ -148,6 +355,27 @@ impl<S: HasStateApi> State<S> {
         self.metadatas.get(token_id).map(|x| x.to_owned())
     }
 
+    /// Replaces the metadata url of a token.
+    /// Results in an error if the token id does not exist in the state or if
+    /// its metadata is frozen.
+    pub fn update_metadata_url(
+        &mut self,
+        token_id: &ContractTokenId,
+        metadata_url: MetadataUrl,
+    ) -> ContractResult<()> {
+        let mut token = self
+            .metadatas
+            .get_mut(token_id)
+            .ok_or(ContractError::InvalidTokenId)?;
+        ensure!(
+            !token.metadata_frozen,
+            ContractError::Custom(CustomContractError::MetadataFrozen)
+        );
+        token.metadata_url = metadata_url;
+
+        Ok(())
+    }
+
     /// Get the current balance of a given token id for a given address.
     /// Results in an error if the token id does not exist in the state.
     pub fn balance(
@@ -206,16 +434,83 @@ impl<S: HasStateApi> State<S> {
         // This allows for the wallet to display the token metadata even if the token is burned
         // self.metadatas.remove(token_id);
         // Remove token from verified tokens.
+        for verifier in self.get_verifiers(token_id).unwrap_or_default() {
+            self.remove_verifier_token(&verifier, token_id);
+        }
         self.verified_tokens.remove(token_id);
 
         Ok(())
     }
 
+    /// Records the retirement of a token and returns its certificate.
+    pub fn add_retirement(
+        &mut self,
+        token_id: ContractTokenId,
+        amount: ContractTokenAmount,
+        owner: Address,
+        retired_at: Timestamp,
+        details: RetirementDetails,
+    ) -> ContractRetirementCertificate {
+        let certificate = ContractRetirementCertificate {
+            certificate_id: self.next_certificate_id,
+            token_id,
+            amount,
+            owner,
+            retired_at,
+            details,
+        };
+        self.retirements
+            .insert(certificate.certificate_id, certificate.clone());
+        self.next_certificate_id += 1;
+
+        certificate
+    }
+
+    /// Gets the certificate of a retirement.
+    pub fn get_retirement(
+        &self,
+        certificate_id: &CertificateId,
+    ) -> Option<ContractRetirementCertificate> {
+        self.retirements.get(certificate_id).map(|c| c.to_owned())
+    }
+
+    /// Check if an address is an operator of a given owner address.
+    pub fn is_operator(&self, address: &Address, owner: &Address) -> bool {
+        self.operators
+            .get(owner)
+            .map(|operators| operators.contains(address))
+            .unwrap_or(false)
+    }
+
+    /// Update the state adding a new operator for a given address.
+    /// Succeeds even if the `operator` is already an operator for the
+    /// `address`.
+    pub fn add_operator(
+        &mut self,
+        owner: &Address,
+        operator: &Address,
+        state_builder: &mut StateBuilder<S>,
+    ) {
+        let mut owner_operators = self
+            .operators
+            .entry(*owner)
+            .or_insert_with(|| state_builder.new_set());
+        owner_operators.insert(*operator);
+    }
+
+    /// Update the state removing an operator for a given address.
+    /// Succeeds even if the `operator` is not an operator for the `address`.
+    pub fn remove_operator(&mut self, owner: &Address, operator: &Address) {
+        self.operators.entry(*owner).and_modify(|operators| {
+            operators.remove(operator);
+        });
+    }
+
     /// Remove a token from an address.
     fn remove_balances(
         &mut self,
         address: &Address,
-        token_id: &TokenIdU8,
+        token_id: &ContractTokenId,
     ) -> Result<(), Cis2Error<super::error::CustomContractError>> {
         self.balances
             .get_mut(address)
@@ -224,3 +519,118 @@ impl<S: HasStateApi> State<S> {
         Ok(())
     }
 }
+
+/// Helpers to set up the state in the tests of the entrypoints.
+#[concordium_cfg_test]
+pub(crate) mod test_utils {
+    use super::*;
+    use crate::client_utils::types::{
+        MaturityTranche, ReportingPeriod, SerialRange, MATURITY_BASIS_POINTS,
+    };
+    use concordium_std::test_infrastructure::*;
+
+    pub const ADMIN: Address = Address::Account(AccountAddress([0u8; 32]));
+    pub const OWNER: Address = Address::Account(AccountAddress([1u8; 32]));
+    pub const VERIFIER_0: Address = Address::Account(AccountAddress([10u8; 32]));
+    pub const VERIFIER_1: Address = Address::Account(AccountAddress([11u8; 32]));
+
+    /// A schedule by which all of the credits mature at the time.
+    pub fn maturity(time: u64) -> MaturitySchedule {
+        MaturitySchedule {
+            tranches: vec![MaturityTranche {
+                time: Timestamp::from_timestamp_millis(time),
+                fraction: MATURITY_BASIS_POINTS,
+            }],
+        }
+    }
+
+    pub fn mint_param() -> MintParam {
+        MintParam {
+            metadata_url: ContractMetadataUrl {
+                url: "https://example.com/project/1.json".to_string(),
+                hash: None,
+            },
+            maturity: maturity(0),
+            project_info: ProjectInfo {
+                vintage: 2023,
+                registry: "Verra".to_string(),
+                serial_range: SerialRange {
+                    start: "1".to_string(),
+                    end: "100".to_string(),
+                },
+                methodology: "VM0015".to_string(),
+                country: "BR".to_string(),
+                project_type: "Reforestation".to_string(),
+            },
+        }
+    }
+
+    /// A state administered by `ADMIN` with a token owned by `OWNER`.
+    pub fn state_with_token(
+        state_builder: &mut TestStateBuilder,
+    ) -> (State<TestStateApi>, ContractTokenId) {
+        let mut state = State::empty(state_builder, ADMIN, false);
+        let mint_param = mint_param();
+        let metadata_url = state
+            .metadata_url(mint_param.metadata_url.clone())
+            .expect_report("Valid metadata url");
+        let token_id = state
+            .mint(&mint_param, metadata_url, &OWNER, state_builder)
+            .expect_report("Token is minted");
+
+        (state, token_id)
+    }
+
+    pub fn attestation() -> Attestation {
+        Attestation {
+            methodology_id: "VM0015".to_string(),
+            reporting_period: ReportingPeriod {
+                start: Timestamp::from_timestamp_millis(1),
+                end: Timestamp::from_timestamp_millis(2),
+            },
+            report_url: "https://example.com/report/1.pdf".to_string(),
+            report_hash: HashSha2256([2u8; 32]),
+        }
+    }
+
+    /// `state_with_token` where `VERIFIER_0` and `VERIFIER_1` are verifiers
+    /// which both verified the token.
+    pub fn state_with_verified_token(
+        state_builder: &mut TestStateBuilder,
+    ) -> (State<TestStateApi>, ContractTokenId) {
+        let (mut state, token_id) = state_with_token(state_builder);
+        for verifier in [VERIFIER_0, VERIFIER_1] {
+            state.add_verifier(&verifier, state_builder);
+            state.verify_token(&token_id, &verifier, attestation(), state_builder);
+        }
+
+        (state, token_id)
+    }
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::{test_utils::*, *};
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_not_mint_when_token_ids_are_exhausted() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder);
+        state.last_token_id = TokenIdU32(u32::MAX);
+
+        let mint_param = mint_param();
+        let metadata_url = state
+            .metadata_url(mint_param.metadata_url.clone())
+            .expect_report("Valid metadata url");
+        let result = state.mint(&mint_param, metadata_url, &OWNER, &mut state_builder);
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(
+                CustomContractError::TokenIdsExhausted
+            ))
+        );
+        assert!(!state.contains_token(&TokenIdU32(u32::MAX)));
+        assert_eq!(state.last_token_id, TokenIdU32(u32::MAX));
+    }
+}
@@ -32,6 +32,11 @@ pub fn transfer<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(
+        !host.state().paused,
+        ContractError::Custom(CustomContractError::Paused)
+    );
     // Parse the parameter.
     let TransferParams(transfers): TransferParameter = ctx.parameter_cursor().get()?;
     // Get the sender who invoked this contract function.
@@ -47,7 +52,10 @@ pub fn transfer<S: HasStateApi>(
     {
         let (state, builder) = host.state_and_builder();
         // Authenticate the sender for this transfer
-        ensure!(from == sender, ContractError::Unauthorized);
+        ensure!(
+            from == sender || state.is_operator(&sender, &from),
+            ContractError::Unauthorized
+        );
         // Ensure that the amount is 1.
         ensure!(
             amount.cmp(&1.into()).is_eq(),
@@ -84,3 +92,80 @@ pub fn transfer<S: HasStateApi>(
     }
     Ok(())
 }
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::project_token::{state::test_utils::*, update_operator::contract_update_operator};
+    use concordium_std::test_infrastructure::*;
+
+    const OPERATOR: Address = Address::Account(AccountAddress([2u8; 32]));
+    const RECEIVER: AccountAddress = AccountAddress([3u8; 32]);
+
+    #[concordium_test]
+    fn should_transfer_by_operator() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter: TransferParameter = TransferParams(vec![Transfer {
+            token_id,
+            amount: 1.into(),
+            from: OWNER,
+            to: Receiver::Account(RECEIVER),
+            data: AdditionalData::empty(),
+        }]);
+        let parameter_bytes = to_bytes(&parameter);
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        // Only the owner or its operators can transfer.
+        ctx.set_sender(OPERATOR);
+        let result = transfer(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        let update_bytes = to_bytes(&UpdateOperatorParams(vec![UpdateOperator {
+            update: OperatorUpdate::Add,
+            operator: OPERATOR,
+        }]));
+        let mut update_ctx = TestReceiveContext::default();
+        update_ctx.set_sender(OWNER);
+        update_ctx.set_parameter(&update_bytes);
+        let result = contract_update_operator(&update_ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            logger.logs[0],
+            to_bytes(&ContractEvent::UpdateOperator(UpdateOperatorEvent {
+                owner: OWNER,
+                operator: OPERATOR,
+                update: OperatorUpdate::Add,
+            }))
+        );
+        // Operators are kept per owner.
+        assert!(!host.state().is_operator(&OPERATOR, &ADMIN));
+
+        let result = transfer(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().balance(&token_id, &OWNER), Ok(0.into()));
+        assert_eq!(
+            host.state().balance(&token_id, &Address::Account(RECEIVER)),
+            Ok(1.into())
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_transfer_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::default();
+        let result = transfer(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(CustomContractError::Paused))
+        );
+    }
+}
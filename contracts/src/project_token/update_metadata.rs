@@ -0,0 +1,173 @@
+use concordium_std::*;
+
+use crate::client_utils::types::ContractMetadataUrl;
+
+use super::{
+    contract_types::{ContractResult, ContractTokenId},
+    error::*,
+    events::*,
+    state::State,
+};
+
+#[derive(Deserial, Serial, SchemaType)]
+pub struct UpdateMetadataParams {
+    pub token_id: ContractTokenId,
+    pub metadata_url: ContractMetadataUrl,
+}
+
+/// Replaces the metadata url of a token and logs a `TokenMetadata` event.
+/// Can only be called by the owner of the token, before its metadata is frozen
+/// by the first verification.
+///
+/// It rejects if:
+/// - The contract is paused.
+/// - Fails to parse parameter.
+/// - The token does not exist.
+/// - The sender does not own the token.
+/// - The metadata of the token is frozen.
+/// - The hash of the metadata url is malformed, or missing while hashes are
+///   required.
+#[receive(
+    contract = "project_token",
+    name = "updateMetadata",
+    parameter = "UpdateMetadataParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn update_metadata<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Ensure that the contract is not paused.
+    ensure!(
+        !host.state().paused,
+        ContractError::Custom(CustomContractError::Paused)
+    );
+    // Parse the parameter.
+    let UpdateMetadataParams {
+        token_id,
+        metadata_url,
+    } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Only the owner of the token can update its metadata.
+    ensure!(
+        state.balance(&token_id, &ctx.sender())? == 1.into(),
+        ContractError::Unauthorized
+    );
+    // Update the contract state, which rejects if the metadata is frozen.
+    let metadata_url = state.metadata_url(metadata_url)?;
+    state.update_metadata_url(&token_id, metadata_url.clone())?;
+    logger.log(&ContractEvent::TokenMetadata(TokenMetadataEvent {
+        token_id,
+        metadata_url,
+    }))?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::project_token::state::test_utils::*;
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_update_metadata() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&UpdateMetadataParams {
+            token_id,
+            metadata_url: ContractMetadataUrl {
+                url: "https://example.com/project/2.json".to_string(),
+                hash: Some("ab".repeat(32)),
+            },
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+
+        let result = update_metadata(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        let metadata_url = MetadataUrl {
+            url: "https://example.com/project/2.json".to_string(),
+            hash: Some([0xab; 32]),
+        };
+        assert_eq!(
+            host.state()
+                .metadatas
+                .get(&token_id)
+                .map(|token| token.metadata_url.clone()),
+            Some(metadata_url.clone())
+        );
+        assert_eq!(
+            logger.logs,
+            vec![to_bytes(&ContractEvent::TokenMetadata(
+                TokenMetadataEvent {
+                    token_id,
+                    metadata_url,
+                }
+            ))]
+        );
+
+        // Only the owner of the token can update its metadata.
+        ctx.set_sender(ADMIN);
+        let result = update_metadata(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+    }
+
+    #[concordium_test]
+    fn should_not_update_metadata_with_malformed_hash() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        // The hash is not a hex encoded SHA-256 hash.
+        let parameter_bytes = to_bytes(&UpdateMetadataParams {
+            token_id,
+            metadata_url: ContractMetadataUrl {
+                url: "https://example.com/project/2.json".to_string(),
+                hash: Some("not a hash".to_string()),
+            },
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+
+        let result = update_metadata(&ctx, &mut host, &mut logger);
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(
+                CustomContractError::InvalidMetadataHash
+            ))
+        );
+        assert_eq!(
+            host.state()
+                .metadatas
+                .get(&token_id)
+                .map(|token| token.metadata_url.url.clone()),
+            Some(mint_param().metadata_url.url)
+        );
+        assert_eq!(logger.logs.len(), 0);
+    }
+
+    #[concordium_test]
+    fn should_not_update_metadata_when_paused() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, _) = state_with_token(&mut state_builder);
+        state.paused = true;
+        let mut host = TestHost::new(state, state_builder);
+
+        let ctx = TestReceiveContext::default();
+        let result = update_metadata(&ctx, &mut host, &mut TestLogger::init());
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(CustomContractError::Paused))
+        );
+    }
+}
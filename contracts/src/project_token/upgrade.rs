@@ -0,0 +1,124 @@
+use concordium_std::*;
+
+use crate::client_utils::upgrade::{self, UpgradeParams};
+
+use super::{contract_types::ContractResult, error::*, state::State};
+
+/// Upgrades the contract to a new module and calls the migration entrypoint
+/// of the new module, if any. Can only be called by the admin.
+#[receive(
+    contract = "project_token",
+    name = "upgrade",
+    parameter = "UpgradeParams",
+    error = "ContractError",
+    low_level
+)]
+pub fn contract_upgrade<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<S, StateApiType = S>,
+) -> ContractResult<()> {
+    // Read the state with the layout of this module to authenticate the sender.
+    let state: State<S> = host.state().read_root()?;
+    ensure!(
+        ctx.sender() == state.roles.admin(),
+        ContractError::Unauthorized
+    );
+    // Parse the parameter.
+    let params: UpgradeParams = ctx.parameter_cursor().get()?;
+
+    upgrade::upgrade(&ctx.self_address(), host, params)?;
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        client_utils::upgrade::{test_utils::LowLevelTestHost, ContractUpgradeError},
+        project_token::state::{test_utils::*, STATE_VERSION},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    const SELF_ADDRESS: ContractAddress = ContractAddress {
+        index: 1,
+        subindex: 0,
+    };
+
+    fn upgrade_params() -> UpgradeParams {
+        UpgradeParams {
+            module: ModuleReference::new([1u8; 32]),
+            migrate: Some((
+                OwnedEntrypointName::new_unchecked("migrate".to_string()),
+                OwnedParameter::empty(),
+            )),
+        }
+    }
+
+    #[concordium_test]
+    fn should_upgrade_and_migrate() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_upgrade(ModuleReference::new([1u8; 32]), Ok(()));
+        // The migration of the new module bumps the version of the state.
+        host.setup_mock_entrypoint(
+            SELF_ADDRESS,
+            OwnedEntrypointName::new_unchecked("migrate".to_string()),
+            MockFn::new_v1(|_, _, _, state: &mut State<TestStateApi>| {
+                state.version += 1;
+                Ok((true, ()))
+            }),
+        );
+
+        let result = upgrade::upgrade(&SELF_ADDRESS, &mut host, upgrade_params());
+
+        assert!(result.is_ok());
+        assert_eq!(host.state().version, STATE_VERSION + 1);
+        // The tokens are kept.
+        assert!(host.state().contains_token(&token_id));
+    }
+
+    #[concordium_test]
+    fn should_not_upgrade_when_migration_fails() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, _) = state_with_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        host.setup_mock_upgrade(ModuleReference::new([1u8; 32]), Ok(()));
+        host.setup_mock_entrypoint(
+            SELF_ADDRESS,
+            OwnedEntrypointName::new_unchecked("migrate".to_string()),
+            MockFn::returning_err::<()>(CallContractError::Trap),
+        );
+
+        let result = upgrade::upgrade(&SELF_ADDRESS, &mut host, upgrade_params());
+
+        assert_eq!(result, Err(ContractUpgradeError::FailedMigration));
+        assert_eq!(host.state().version, STATE_VERSION);
+    }
+
+    #[concordium_test]
+    fn should_upgrade_by_admin_only() {
+        let mut host = LowLevelTestHost::new();
+        let (state, _) = state_with_token(host.state_builder());
+        host.state_mut().write_root(&state);
+        host.host
+            .setup_mock_upgrade(ModuleReference::new([1u8; 32]), Ok(()));
+
+        let parameter_bytes = to_bytes(&UpgradeParams {
+            module: ModuleReference::new([1u8; 32]),
+            migrate: None,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_self_address(SELF_ADDRESS);
+
+        ctx.set_sender(OWNER);
+        let result = contract_upgrade(&ctx, &mut host);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        ctx.set_sender(ADMIN);
+        let result = contract_upgrade(&ctx, &mut host);
+        assert_eq!(result, Ok(()));
+    }
+}
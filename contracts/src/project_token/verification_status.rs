@@ -0,0 +1,80 @@
+use concordium_std::*;
+
+use crate::client_utils::types::VerificationStatusQueryResponse;
+
+use super::{
+    contract_types::{ContractIsVerifiedQueryParams, ContractResult},
+    state::State,
+};
+
+/// Returns the verification status of the given tokens. A token is pending
+/// until the verifiers which verified it meet the quorum.
+#[receive(
+    contract = "project_token",
+    name = "verificationStatus",
+    parameter = "ContractIsVerifiedQueryParams",
+    error = "super::error::ContractError",
+    return_value = "VerificationStatusQueryResponse"
+)]
+pub fn verification_status<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<VerificationStatusQueryResponse> {
+    // Parse the parameter.
+    let ContractIsVerifiedQueryParams { queries } = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    Ok(queries
+        .iter()
+        .map(|token_id| state.verification_status(token_id))
+        .collect())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        client_utils::types::VerificationStatus,
+        project_token::{events::VerifierWeight, state::test_utils::*},
+    };
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_be_pending_until_quorum() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder);
+        state.set_quorum(
+            3,
+            &[VerifierWeight {
+                verifier: VERIFIER_0,
+                weight: 2,
+            }],
+        );
+        let mut host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&ContractIsVerifiedQueryParams {
+            queries: vec![token_id],
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        let result = verification_status(&ctx, &host);
+        assert_eq!(result, Ok(vec![VerificationStatus::Unverified]));
+
+        // The weight of 2 is below the threshold of 3.
+        let (state, builder) = host.state_and_builder();
+        state.add_verifier(&VERIFIER_0, builder);
+        state.verify_token(&token_id, &VERIFIER_0, attestation(), builder);
+        let result = verification_status(&ctx, &host);
+        assert_eq!(result, Ok(vec![VerificationStatus::Pending]));
+        assert!(!host.state().is_verified(&token_id));
+
+        // A verifier without a weight adds a weight of 1.
+        let (state, builder) = host.state_and_builder();
+        state.add_verifier(&VERIFIER_1, builder);
+        state.verify_token(&token_id, &VERIFIER_1, attestation(), builder);
+        let result = verification_status(&ctx, &host);
+        assert_eq!(result, Ok(vec![VerificationStatus::Verified]));
+        assert!(host.state().is_verified(&token_id));
+    }
+}
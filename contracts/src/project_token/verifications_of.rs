@@ -0,0 +1,80 @@
+use concordium_std::*;
+
+use crate::client_utils::types::VerificationsOfQueryResponse;
+
+use super::{
+    contract_types::{ContractIsVerifiedQueryParams, ContractResult},
+    error::*,
+    state::State,
+};
+
+/// Returns the verifications of the given tokens with the attestations of
+/// their verifiers.
+#[receive(
+    contract = "project_token",
+    name = "verificationsOf",
+    parameter = "ContractIsVerifiedQueryParams",
+    error = "ContractError",
+    return_value = "VerificationsOfQueryResponse"
+)]
+pub fn verifications_of<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<VerificationsOfQueryResponse> {
+    // Parse the parameter.
+    let ContractIsVerifiedQueryParams { queries } = ctx.parameter_cursor().get()?;
+    let state = host.state();
+
+    queries
+        .iter()
+        .map(|token_id| {
+            ensure!(
+                state.contains_token(token_id),
+                ContractError::InvalidTokenId
+            );
+            Ok(state.get_verifications(token_id))
+        })
+        .collect()
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{client_utils::types::Verification, project_token::state::test_utils::*};
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_query_verifications() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_verified_token(&mut state_builder);
+        let host = TestHost::new(state, state_builder);
+
+        let parameter_bytes = to_bytes(&ContractIsVerifiedQueryParams {
+            queries: vec![token_id],
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+
+        let result = verifications_of(&ctx, &host);
+        assert_eq!(
+            result,
+            Ok(vec![vec![
+                Verification {
+                    verifier: VERIFIER_0,
+                    attestation: attestation(),
+                },
+                Verification {
+                    verifier: VERIFIER_1,
+                    attestation: attestation(),
+                },
+            ]])
+        );
+
+        let parameter_bytes = to_bytes(&ContractIsVerifiedQueryParams {
+            queries: vec![token_id, 1.into()],
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = verifications_of(&ctx, &host);
+        assert_eq!(result, Err(ContractError::InvalidTokenId));
+    }
+}
@@ -1,5 +1,7 @@
 use concordium_std::*;
 
+use crate::client_utils::types::{Attestation, MaturitySchedule};
+
 use super::{
     contract_types::{ContractResult, ContractTokenId},
     error::*,
@@ -27,14 +29,11 @@ pub fn add_verifier<S: HasStateApi>(
 ) -> ContractResult<()> {
     // Parse the parameter.
     let AddVerifierParams { verifier } = ctx.parameter_cursor().get()?;
-    let state = host.state_mut();
+    let (state, builder) = host.state_and_builder();
     // Authenticate the sender for this transfer
-    ensure!(
-        ctx.sender().matches_account(&ctx.owner()),
-        ContractError::Unauthorized
-    );
+    ensure!(state.is_admin(&ctx.sender()), ContractError::Unauthorized);
     // Update the contract state
-    state.add_verifier(&verifier);
+    state.add_verifier(&verifier, builder);
     logger.log(&ContractEvent::VerifierAdded(VerifierUpdatedEvent {
         verifier,
     }))?;
@@ -45,6 +44,8 @@ pub fn add_verifier<S: HasStateApi>(
 #[derive(Deserial, Serial, SchemaType)]
 pub struct RemoveVerifierParams {
     pub verifier: Address,
+    /// Whether to invalidate the verifications of the verifier or keep them.
+    pub invalidate_verifications: bool,
 }
 
 #[receive(
@@ -61,18 +62,75 @@ pub fn remove_verifier<S: HasStateApi>(
     logger: &mut impl HasLogger,
 ) -> ContractResult<()> {
     // Parse the parameter.
-    let RemoveVerifierParams { verifier } = ctx.parameter_cursor().get()?;
+    let RemoveVerifierParams {
+        verifier,
+        invalidate_verifications,
+    } = ctx.parameter_cursor().get()?;
     let state = host.state_mut();
     // Authenticate the sender for this transfer
-    ensure!(
-        ctx.sender().matches_account(&ctx.owner()),
-        ContractError::Unauthorized
-    );
+    ensure!(state.is_admin(&ctx.sender()), ContractError::Unauthorized);
     // Update the contract state
     state.remove_verifier(&verifier);
+    let token_ids = state.tokens_verified_by(&verifier);
+    if invalidate_verifications {
+        for token_id in token_ids.iter() {
+            state.unverify_token(token_id, &verifier);
+        }
+    }
     logger.log(&ContractEvent::VerifierRemoved(VerifierUpdatedEvent {
         verifier,
     }))?;
+    logger.log(&ContractEvent::VerificationsAffected(
+        VerificationsAffectedEvent {
+            verifier,
+            token_ids,
+            invalidated: invalidate_verifications,
+        },
+    ))?;
+
+    Ok(())
+}
+
+#[derive(Deserial, Serial, SchemaType)]
+pub struct SetQuorumParams {
+    /// Total weight of the verifiers needed for a token to be verified.
+    pub threshold: u32,
+    /// Weights of the verifiers to update.
+    pub weights: Vec<VerifierWeight>,
+}
+
+/// Sets the quorum of verifiers needed to verify a token. A threshold of N with
+/// verifiers of weight 1 requires N verifiers.
+#[receive(
+    contract = "project_token",
+    name = "setQuorum",
+    parameter = "SetQuorumParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn set_quorum<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    // Parse the parameter.
+    let SetQuorumParams { threshold, weights } = ctx.parameter_cursor().get()?;
+    // Authenticate the sender
+    ensure!(
+        host.state().is_admin(&ctx.sender()),
+        ContractError::Unauthorized
+    );
+    ensure!(
+        threshold > 0,
+        ContractError::Custom(CustomContractError::InvalidQuorum)
+    );
+    // Update the contract state
+    host.state_mut().set_quorum(threshold, &weights);
+    logger.log(&ContractEvent::QuorumUpdated(QuorumUpdatedEvent {
+        threshold,
+        weights,
+    }))?;
 
     Ok(())
 }
@@ -80,6 +138,7 @@ pub fn remove_verifier<S: HasStateApi>(
 #[derive(Deserial, Serial, SchemaType)]
 pub struct VerifyParams {
     pub token_id: ContractTokenId,
+    pub attestation: Attestation,
 }
 
 #[receive(
@@ -97,18 +156,571 @@ fn verify<S: HasStateApi>(
 ) -> ContractResult<()> {
     let verifier = ctx.sender();
     // Parse the parameter.
-    let VerifyParams { token_id } = ctx.parameter_cursor().get()?;
+    let VerifyParams {
+        token_id,
+        attestation,
+    } = ctx.parameter_cursor().get()?;
     // Get the sender who invoked this contract function.
     let (state, builder) = host.state_and_builder();
     // Authenticate the sender for this transfer
     ensure!(state.is_verifier(&verifier), ContractError::Unauthorized);
+    ensure!(
+        state.contains_token(&token_id),
+        ContractError::InvalidTokenId
+    );
+    ensure!(
+        attestation.reporting_period.start <= attestation.reporting_period.end,
+        ContractError::Custom(CustomContractError::InvalidReportingPeriod)
+    );
     // Update the contract state
-    state.verify_token(&token_id, &verifier, builder);
+    state.verify_token(&token_id, &verifier, attestation, builder);
+    // The first verification freezes the metadata of the token.
+    let metadata_frozen = state.freeze_metadata(&token_id);
     // Log the event
     logger.log(&ContractEvent::Verification(VerificationEvent {
         token_id,
         verifier,
     }))?;
+    if metadata_frozen {
+        logger.log(&ContractEvent::MetadataFrozen(MetadataFrozenEvent {
+            token_id,
+        }))?;
+    }
 
     Ok(())
 }
+
+#[derive(Deserial, Serial, SchemaType)]
+pub struct SetMaturityParams {
+    pub token_id: ContractTokenId,
+    pub maturity: MaturitySchedule,
+    /// Why the schedule is amended.
+    pub reason: String,
+}
+
+/// Amends the maturity schedule of a token, e.g. when the project timeline
+/// slipped. Can only be called by a verifier which verified the token, or by
+/// the admin. The schedule is not part of the metadata frozen by the first
+/// verification, so it can be amended after it. Logs a `MaturityTime` event
+/// with the new schedule and a `MaturityAmended` event with the reason.
+#[receive(
+    contract = "project_token",
+    name = "setMaturity",
+    parameter = "SetMaturityParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+pub fn set_maturity<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let verifier = ctx.sender();
+    // Parse the parameter.
+    let SetMaturityParams {
+        token_id,
+        maturity,
+        reason,
+    } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Authenticate the sender
+    ensure!(
+        state.is_admin(&verifier)
+            || (state.is_verifier(&verifier) && state.is_verified_by(&token_id, &verifier)),
+        ContractError::Unauthorized
+    );
+    ensure!(
+        maturity.is_valid(),
+        ContractError::Custom(CustomContractError::InvalidMaturitySchedule)
+    );
+    // Update the contract state
+    state.set_maturity(&token_id, maturity.clone())?;
+    // Log the events
+    logger.log(&ContractEvent::MaturityTime(MaturityTimeEvent {
+        token_id,
+        maturity,
+    }))?;
+    logger.log(&ContractEvent::MaturityAmended(MaturityAmendedEvent {
+        token_id,
+        verifier,
+        reason,
+    }))?;
+
+    Ok(())
+}
+
+#[derive(Deserial, Serial, SchemaType)]
+pub struct UnverifyParams {
+    pub token_id: ContractTokenId,
+    pub reason: RevocationReason,
+}
+
+/// Revokes the verification of a token. A verifier revokes its own
+/// verification, an admin revokes the verifications of all verifiers.
+#[receive(
+    contract = "project_token",
+    name = "unverify",
+    parameter = "UnverifyParams",
+    error = "ContractError",
+    enable_logger,
+    mutable
+)]
+fn unverify<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    let sender = ctx.sender();
+    // Parse the parameter.
+    let UnverifyParams { token_id, reason } = ctx.parameter_cursor().get()?;
+    let state = host.state_mut();
+    // Authenticate the sender and get the verifications to revoke
+    let verifiers = if state.is_admin(&sender) {
+        state.get_verifiers(&token_id).unwrap_or_default()
+    } else {
+        ensure!(state.is_verifier(&sender), ContractError::Unauthorized);
+        vec![sender]
+    };
+
+    let mut revoked = false;
+    for verifier in verifiers {
+        if !state.unverify_token(&token_id, &verifier) {
+            continue;
+        }
+        revoked = true;
+        // Log the event
+        logger.log(&ContractEvent::VerificationRevoked(
+            VerificationRevokedEvent {
+                token_id,
+                verifier,
+                revoked_by: sender,
+                reason,
+            },
+        ))?;
+    }
+    ensure!(
+        revoked,
+        ContractError::Custom(CustomContractError::TokenNotVerified)
+    );
+
+    Ok(())
+}
+
+#[concordium_cfg_test]
+mod test {
+    use super::*;
+    use crate::{
+        client_utils::types::{
+            MaturityTranche, ReportingPeriod, Verification, MATURITY_BASIS_POINTS,
+        },
+        project_token::{
+            contract_types::ContractMaturityOfQueryParams,
+            maturity_of::maturity_of,
+            state::test_utils::*,
+            update_metadata::{update_metadata, UpdateMetadataParams},
+        },
+    };
+    use concordium_std::test_infrastructure::*;
+
+    #[concordium_test]
+    fn should_verify_with_attestation() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder);
+        state.add_verifier(&VERIFIER_0, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&VerifyParams {
+            token_id,
+            attestation: attestation(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(VERIFIER_0);
+
+        let result = verify(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            host.state().get_verifications(&token_id),
+            vec![Verification {
+                verifier: VERIFIER_0,
+                attestation: attestation(),
+            }]
+        );
+        assert_eq!(
+            logger.logs[0],
+            to_bytes(&ContractEvent::Verification(VerificationEvent {
+                token_id,
+                verifier: VERIFIER_0,
+            }))
+        );
+
+        // A new attestation of the verifier replaces the previous one.
+        let new_attestation = Attestation {
+            report_url: "https://example.com/report/2.pdf".to_string(),
+            report_hash: HashSha2256([3u8; 32]),
+            ..attestation()
+        };
+        let parameter_bytes = to_bytes(&VerifyParams {
+            token_id,
+            attestation: new_attestation.clone(),
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = verify(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            host.state().get_verifications(&token_id),
+            vec![Verification {
+                verifier: VERIFIER_0,
+                attestation: new_attestation,
+            }]
+        );
+    }
+
+    #[concordium_test]
+    fn should_freeze_metadata_on_first_verification() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder);
+        state.add_verifier(&VERIFIER_0, &mut state_builder);
+        state.add_verifier(&VERIFIER_1, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&VerifyParams {
+            token_id,
+            attestation: attestation(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        for verifier in [VERIFIER_0, VERIFIER_1] {
+            ctx.set_sender(verifier);
+            let result = verify(&ctx, &mut host, &mut logger);
+            assert_eq!(result, Ok(()));
+        }
+
+        // Only the first verification freezes the metadata.
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::Verification(VerificationEvent {
+                    token_id,
+                    verifier: VERIFIER_0,
+                })),
+                to_bytes(&ContractEvent::MetadataFrozen(MetadataFrozenEvent {
+                    token_id
+                })),
+                to_bytes(&ContractEvent::Verification(VerificationEvent {
+                    token_id,
+                    verifier: VERIFIER_1,
+                })),
+            ]
+        );
+
+        // The owner can no longer update the metadata.
+        let parameter_bytes = to_bytes(&UpdateMetadataParams {
+            token_id,
+            metadata_url: mint_param().metadata_url,
+        });
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+        let result = update_metadata(&ctx, &mut host, &mut logger);
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(CustomContractError::MetadataFrozen))
+        );
+    }
+
+    #[concordium_test]
+    fn should_not_verify_with_invalid_attestation() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder);
+        state.add_verifier(&VERIFIER_0, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let mut invalid_attestation = attestation();
+        invalid_attestation.reporting_period = ReportingPeriod {
+            start: invalid_attestation.reporting_period.end,
+            end: invalid_attestation.reporting_period.start,
+        };
+        let parameter_bytes = to_bytes(&VerifyParams {
+            token_id,
+            attestation: invalid_attestation,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(VERIFIER_0);
+
+        let result = verify(&ctx, &mut host, &mut logger);
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(
+                CustomContractError::InvalidReportingPeriod
+            ))
+        );
+
+        // Only verifiers can verify tokens.
+        let parameter_bytes = to_bytes(&VerifyParams {
+            token_id,
+            attestation: attestation(),
+        });
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(OWNER);
+        let result = verify(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(host.state().get_verifications(&token_id), vec![]);
+        assert_eq!(logger.logs.len(), 0);
+    }
+
+    #[concordium_test]
+    fn should_log_verifications_affected_by_removed_verifier() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_verified_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_sender(ADMIN);
+
+        let parameter_bytes = to_bytes(&RemoveVerifierParams {
+            verifier: VERIFIER_0,
+            invalidate_verifications: true,
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = remove_verifier(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert!(!host.state().is_verifier(&VERIFIER_0));
+        assert_eq!(
+            host.state().get_verifiers(&token_id),
+            Some(vec![VERIFIER_1])
+        );
+        assert_eq!(host.state().tokens_verified_by(&VERIFIER_0), vec![]);
+
+        // The verifications of a verifier removed without invalidation are kept.
+        let parameter_bytes = to_bytes(&RemoveVerifierParams {
+            verifier: VERIFIER_1,
+            invalidate_verifications: false,
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = remove_verifier(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            host.state().get_verifiers(&token_id),
+            Some(vec![VERIFIER_1])
+        );
+        assert_eq!(host.state().tokens_verified_by(&VERIFIER_1), vec![token_id]);
+
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::VerifierRemoved(VerifierUpdatedEvent {
+                    verifier: VERIFIER_0,
+                })),
+                to_bytes(&ContractEvent::VerificationsAffected(
+                    VerificationsAffectedEvent {
+                        verifier: VERIFIER_0,
+                        token_ids: vec![token_id],
+                        invalidated: true,
+                    }
+                )),
+                to_bytes(&ContractEvent::VerifierRemoved(VerifierUpdatedEvent {
+                    verifier: VERIFIER_1,
+                })),
+                to_bytes(&ContractEvent::VerificationsAffected(
+                    VerificationsAffectedEvent {
+                        verifier: VERIFIER_1,
+                        token_ids: vec![token_id],
+                        invalidated: false,
+                    }
+                )),
+            ]
+        );
+    }
+
+    #[concordium_test]
+    fn should_revoke_all_verifications_by_admin() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_verified_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&UnverifyParams {
+            token_id,
+            reason: RevocationReason::Fraud,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(ADMIN);
+
+        let result = unverify(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.state().get_verifiers(&token_id), None);
+        assert!(!host.state().is_verified(&token_id));
+
+        let mut logs = logger.logs.clone();
+        logs.sort();
+        let mut expected_logs: Vec<Vec<u8>> = [VERIFIER_0, VERIFIER_1]
+            .iter()
+            .map(|verifier| {
+                to_bytes(&ContractEvent::VerificationRevoked(
+                    VerificationRevokedEvent {
+                        token_id,
+                        verifier: *verifier,
+                        revoked_by: ADMIN,
+                        reason: RevocationReason::Fraud,
+                    },
+                ))
+            })
+            .collect();
+        expected_logs.sort();
+        assert_eq!(logs, expected_logs);
+
+        // There is nothing left to revoke.
+        let result = unverify(&ctx, &mut host, &mut logger);
+        assert_eq!(
+            result,
+            Err(ContractError::Custom(CustomContractError::TokenNotVerified))
+        );
+    }
+
+    #[concordium_test]
+    fn should_revoke_own_verification_by_verifier() {
+        let mut state_builder = TestStateBuilder::new();
+        let (state, token_id) = state_with_verified_token(&mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&UnverifyParams {
+            token_id,
+            reason: RevocationReason::Error,
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(VERIFIER_0);
+
+        let result = unverify(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        // The verification of the other verifier is kept.
+        assert_eq!(
+            host.state().get_verifiers(&token_id),
+            Some(vec![VERIFIER_1])
+        );
+        assert_eq!(
+            logger.logs,
+            vec![to_bytes(&ContractEvent::VerificationRevoked(
+                VerificationRevokedEvent {
+                    token_id,
+                    verifier: VERIFIER_0,
+                    revoked_by: VERIFIER_0,
+                    reason: RevocationReason::Error,
+                }
+            ))]
+        );
+
+        // Only verifiers and admins can revoke verifications.
+        ctx.set_sender(OWNER);
+        let result = unverify(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+    }
+
+    #[concordium_test]
+    fn should_amend_maturity_by_verifier_of_token() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_verified_token(&mut state_builder);
+        state.freeze_metadata(&token_id);
+        let mut host = TestHost::new(state, state_builder);
+        let mut logger = TestLogger::init();
+
+        let parameter_bytes = to_bytes(&SetMaturityParams {
+            token_id,
+            maturity: maturity(2000),
+            reason: "Monitoring period delayed".to_string(),
+        });
+        let mut ctx = TestReceiveContext::default();
+        ctx.set_parameter(&parameter_bytes);
+        ctx.set_sender(VERIFIER_0);
+
+        // The schedule can be amended after the metadata is frozen.
+        let result = set_maturity(&ctx, &mut host, &mut logger);
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            logger.logs,
+            vec![
+                to_bytes(&ContractEvent::MaturityTime(MaturityTimeEvent {
+                    token_id,
+                    maturity: maturity(2000),
+                })),
+                to_bytes(&ContractEvent::MaturityAmended(MaturityAmendedEvent {
+                    token_id,
+                    verifier: VERIFIER_0,
+                    reason: "Monitoring period delayed".to_string(),
+                })),
+            ]
+        );
+
+        let parameter_bytes = to_bytes(&ContractMaturityOfQueryParams {
+            queries: vec![token_id],
+        });
+        ctx.set_parameter(&parameter_bytes);
+        let result = maturity_of(&ctx, &host);
+        assert_eq!(result, Ok(vec![maturity(2000)]));
+    }
+
+    #[concordium_test]
+    fn should_not_amend_maturity_by_others() {
+        let mut state_builder = TestStateBuilder::new();
+        let (mut state, token_id) = state_with_token(&mut state_builder);
+        // The verifier has not verified the token.
+        state.add_verifier(&VERIFIER_0, &mut state_builder);
+        let mut host = TestHost::new(state, state_builder);
+
+        let set = |host: &mut TestHost<State<TestStateApi>>, sender, maturity| {
+            let parameter_bytes = to_bytes(&SetMaturityParams {
+                token_id,
+                maturity,
+                reason: "Monitoring period delayed".to_string(),
+            });
+            let mut ctx = TestReceiveContext::default();
+            ctx.set_parameter(&parameter_bytes);
+            ctx.set_sender(sender);
+            set_maturity(&ctx, host, &mut TestLogger::init())
+        };
+
+        assert_eq!(
+            set(&mut host, VERIFIER_0, maturity(2000)),
+            Err(ContractError::Unauthorized)
+        );
+        assert_eq!(
+            set(&mut host, OWNER, maturity(2000)),
+            Err(ContractError::Unauthorized)
+        );
+        // The fractions of the schedule do not add up to 100%.
+        let invalid_maturity = MaturitySchedule {
+            tranches: vec![MaturityTranche {
+                time: Timestamp::from_timestamp_millis(2000),
+                fraction: MATURITY_BASIS_POINTS / 2,
+            }],
+        };
+        assert_eq!(
+            set(&mut host, ADMIN, invalid_maturity),
+            Err(ContractError::Custom(
+                CustomContractError::InvalidMaturitySchedule
+            ))
+        );
+        assert_eq!(
+            host.state()
+                .get_token(&token_id)
+                .map(|token| token.maturity),
+            Some(maturity(0))
+        );
+
+        // The admin can amend the schedule of any token.
+        assert_eq!(set(&mut host, ADMIN, maturity(2000)), Ok(()));
+        assert_eq!(
+            host.state()
+                .get_token(&token_id)
+                .map(|token| token.maturity),
+            Some(maturity(2000))
+        );
+    }
+}
@@ -16,6 +16,7 @@ interface MarketplaceAddProps {
   nftContractAddress: ContractAddress;
   cis2Contract: CIS2Contract;
   tokenId: string;
+  quantity: string;
   onDone: () => void;
 }
 
@@ -34,6 +35,7 @@ function MarketplaceAdd(props: MarketplaceAddProps) {
     const formData = new FormData(event.currentTarget);
     const price = formData.get("price")?.toString() || "";
     const royalty = formData.get("royalty")?.toString() || "0";
+    const quantity = formData.get("quantity")?.toString() || "";
 
     if (!price || BigInt(price) <= 0) {
       setState({ ...state, error: "Invalid Price" });
@@ -45,6 +47,11 @@ function MarketplaceAdd(props: MarketplaceAddProps) {
       return;
     }
 
+    if (!quantity || BigInt(quantity) <= 0) {
+      setState({ ...state, error: "Invalid Quantity" });
+      return;
+    }
+
     setState({ ...state, inProgress: true, error: "" });
 
     const paramJson: AddParams = {
@@ -52,6 +59,9 @@ function MarketplaceAdd(props: MarketplaceAddProps) {
       royalty: parseInt(royalty) * 100, //conversion to basis points
       cis_contract_address: toParamContractAddress(props.nftContractAddress),
       token_id: props.tokenId,
+      quantity,
+      // The token has been transferred to the Marketplace.
+      mode: { Custody: [] },
     };
 
     connectToWallet()
@@ -104,6 +114,17 @@ function MarketplaceAdd(props: MarketplaceAddProps) {
         disabled={state.inProgress}
         required
       />
+      <TextField
+        name="quantity"
+        id="quantity"
+        type="number"
+        label="Quantity"
+        variant="standard"
+        fullWidth
+        disabled={state.inProgress}
+        required
+        defaultValue={props.quantity}
+      />
       <TextField
         name="royalty"
         id="royalty"
@@ -68,6 +68,7 @@ export default function MarketplaceTransferDialog(props: {
           item.tokenId,
           item.owner,
           quantity,
+          item.price,
           totalAmount,
           "widget",
         );
@@ -36,6 +36,7 @@ export default function FractionalizerContractInit(props: {
           wallet.account,
           {
             verifier_contracts: [{ index: Number(form.index), subindex: Number(form.subindex) }],
+            metadata_hash_required: false,
           } as unknown as SmartContractParameters,
           BigInt(9999),
           BigInt(0),
@@ -17,6 +17,10 @@ export function FractionalizerRetire(props: {
   const [form, setForm] = useState({
     tokenId: "",
     amount: "1",
+    beneficiary: "",
+    reason: "",
+    reportingPeriodStart: "",
+    reportingPeriodEnd: "",
   });
   const [txn, setTxn] = useState<{ hash: string; status: TransactionStatusEnum }>();
 
@@ -39,6 +43,11 @@ export function FractionalizerRetire(props: {
       return;
     }
 
+    if (!form.reportingPeriodStart || !form.reportingPeriodEnd) {
+      setState({ ...state, isProcessing: false, error: "Reporting period is required" });
+      return;
+    }
+
     setState({ ...state, isProcessing: true, error: "" });
 
     setTxn(undefined);
@@ -49,7 +58,20 @@ export function FractionalizerRetire(props: {
           wallet.account,
           props.address,
           props.contractInfo,
-          { owner: { Account: [wallet.account] }, tokens: [{ token_id: form.tokenId, amount: form.amount }] },
+          {
+            owner: { Account: [wallet.account] },
+            tokens: [{ token_id: form.tokenId, amount: form.amount }],
+            details: {
+              beneficiary: form.beneficiary
+                ? { Name: [form.beneficiary] }
+                : { Address: [{ Account: [wallet.account] }] },
+              reason: form.reason,
+              reporting_period: {
+                start: new Date(form.reportingPeriodStart).toISOString(),
+                end: new Date(form.reportingPeriodEnd).toISOString(),
+              },
+            },
+          },
           BigInt(19999),
           (status, hash) => setTxn({ status, hash }),
         ),
@@ -84,6 +106,42 @@ export function FractionalizerRetire(props: {
           onChange={(e) => setForm({ ...form, amount: e.target.value })}
           value={form.amount}
         />
+        <TextField
+          label="Beneficiary (Defaults to your account)"
+          name="beneficiary"
+          variant="standard"
+          fullWidth
+          onChange={(e) => setForm({ ...form, beneficiary: e.target.value })}
+          value={form.beneficiary}
+        />
+        <TextField
+          label="Reason"
+          name="reason"
+          variant="standard"
+          fullWidth
+          onChange={(e) => setForm({ ...form, reason: e.target.value })}
+          value={form.reason}
+        />
+        <TextField
+          label="Reporting Period Start"
+          name="reportingPeriodStart"
+          variant="standard"
+          fullWidth
+          type="datetime-local"
+          InputLabelProps={{ shrink: true }}
+          onChange={(e) => setForm({ ...form, reportingPeriodStart: e.target.value })}
+          value={form.reportingPeriodStart}
+        />
+        <TextField
+          label="Reporting Period End"
+          name="reportingPeriodEnd"
+          variant="standard"
+          fullWidth
+          type="datetime-local"
+          InputLabelProps={{ shrink: true }}
+          onChange={(e) => setForm({ ...form, reportingPeriodEnd: e.target.value })}
+          value={form.reportingPeriodEnd}
+        />
         <DisplayError error={state.error} />
         <Button type="submit" variant="contained" color="primary">
           Retire
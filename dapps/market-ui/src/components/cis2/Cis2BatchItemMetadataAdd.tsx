@@ -10,7 +10,7 @@ import {
 } from '@mui/material';
 
 import { ContractInfo } from '../../models/ConcordiumContractClient';
-import { Metadata, TokenInfo } from '../../models/ProjectNFTClient';
+import { Metadata, ProjectInfo, TokenInfo } from '../../models/ProjectNFTClient';
 import DisplayError from '../ui/DisplayError';
 import LazyCis2Metadata from './LazyCis2Metadata';
 import GetMaturityTimeCardStep from './metadata-prepare-steps/GetMaturityTimeCardStep';
@@ -141,10 +141,10 @@ function Cis2BatchItemMetadataAdd(props: {
     setStep(Steps.GetMaturityTime);
   }
 
-  function maturityTimeUpdated(maturityTime: Date) {
+  function maturityTimeUpdated(maturityTime: Date, projectInfo: ProjectInfo) {
     setMaturityTime(maturityTime);
     setStep(Steps.Mint);
-    props.onDone({ tokenId, tokenInfo: { metadataUrl: metadataUrl!, maturityTime } });
+    props.onDone({ tokenId, tokenInfo: { metadataUrl: metadataUrl!, maturityTime, projectInfo } });
   }
 
   switch (step) {
@@ -161,7 +161,7 @@ function Cis2BatchItemMetadataAdd(props: {
         <GetMaturityTimeCardStep
           tokenId={tokenId}
           imageUrl={metadata?.display?.url}
-          onDone={({ maturityTime }) => maturityTimeUpdated(maturityTime)}
+          onDone={({ maturityTime, projectInfo }) => maturityTimeUpdated(maturityTime, projectInfo)}
         />
       );
     case Steps.Mint:
@@ -5,7 +5,7 @@ import { SxProps } from '@mui/material';
 import { Theme } from '@mui/system';
 
 import { PinataClient } from '../../models/PinataClient';
-import { TokenInfo } from '../../models/ProjectNFTClient';
+import { ProjectInfo, TokenInfo } from '../../models/ProjectNFTClient';
 import GetMaturityTimeCardStep from './metadata-prepare-steps/GetMaturityTimeCardStep';
 import GetMintCardStep from './metadata-prepare-steps/GetMintCardStep';
 import UploadArtifactIpfsCardStep from './metadata-prepare-steps/UploadArtifactIpfsCardStep';
@@ -56,10 +56,10 @@ function Cis2BatchItemMetadataPrepare(props: {
     goForward();
   }
 
-  function maturityTimeUpdated(tokenId: string, maturityTime: Date) {
+  function maturityTimeUpdated(tokenId: string, maturityTime: Date, projectInfo: ProjectInfo) {
     setMaturityTime(maturityTime);
     goForward();
-    props.onDone({ tokenId, tokenInfo: { metadataUrl: metadataUrl!, maturityTime } });
+    props.onDone({ tokenId, tokenInfo: { metadataUrl: metadataUrl!, maturityTime, projectInfo } });
   }
 
   switch (step) {
@@ -104,7 +104,7 @@ function Cis2BatchItemMetadataPrepare(props: {
           imageUrl={imageDisplayUrl}
           tokenId={tokenId}
           key={tokenId}
-          onDone={(data) => maturityTimeUpdated(data.tokenId, data.maturityTime)}
+          onDone={(data) => maturityTimeUpdated(data.tokenId, data.maturityTime, data.projectInfo)}
         />
       );
     case Steps.Mint:
@@ -1,5 +1,6 @@
 import React, { FormEvent, useState } from 'react';
 
+import { SmartContractParameters } from '@concordium/browser-wallet-api-helpers';
 import { ConcordiumGRPCClient, ContractAddress, TransactionStatusEnum } from '@concordium/web-sdk';
 import { Button, Stack, Typography } from '@mui/material';
 
@@ -28,7 +29,7 @@ function Cis2Init(props: {
           wallet.provider,
           props.contractInfo,
           wallet.account,
-          undefined,
+          { metadata_hash_required: false } as unknown as SmartContractParameters,
           BigInt(9999),
           BigInt(0),
           (status, hash) => setTxn({ status, hash }),
@@ -74,7 +74,11 @@ function MaturityTimeEvent(props: { event: ProjectNftMaturityTimeEvent }) {
         secondary={
           <>
             <Typography component="div">Token {event.token_id}</Typography>
-            <Typography component="div">Time {event.maturity_time}</Typography>
+            {event.maturity.tranches.map((tranche) => (
+              <Typography component="div" key={tranche.time}>
+                Time {tranche.time}, Fraction {tranche.fraction / 100}%
+              </Typography>
+            ))}
           </>
         }
       />
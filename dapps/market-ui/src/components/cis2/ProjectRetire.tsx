@@ -16,6 +16,10 @@ export function ProjectRetire(props: {
 }) {
   const [form, setForm] = useState({
     tokenId: "",
+    beneficiary: "",
+    reason: "",
+    reportingPeriodStart: "",
+    reportingPeriodEnd: "",
   });
   const [txn, setTxn] = useState<{ hash: string; status: TransactionStatusEnum }>();
 
@@ -26,6 +30,11 @@ export function ProjectRetire(props: {
 
   const onsubmit = async (e: React.FormEvent<HTMLFormElement>) => {
     e.preventDefault();
+    if (!form.reportingPeriodStart || !form.reportingPeriodEnd) {
+      setState({ ...state, isProcessing: false, error: "Reporting period is required" });
+      return;
+    }
+
     setState({
       ...state,
       isProcessing: true,
@@ -40,7 +49,20 @@ export function ProjectRetire(props: {
           wallet.account,
           props.address,
           props.contractInfo,
-          { owner: { Account: [wallet.account] }, tokens: [{ token_id: form.tokenId, amount: "1" }] },
+          {
+            owner: { Account: [wallet.account] },
+            tokens: [{ token_id: form.tokenId, amount: "1" }],
+            details: {
+              beneficiary: form.beneficiary
+                ? { Name: [form.beneficiary] }
+                : { Address: [{ Account: [wallet.account] }] },
+              reason: form.reason,
+              reporting_period: {
+                start: new Date(form.reportingPeriodStart).toISOString(),
+                end: new Date(form.reportingPeriodEnd).toISOString(),
+              },
+            },
+          },
           BigInt(9999),
           (status, hash) => setTxn({ status, hash }),
         ),
@@ -66,6 +88,42 @@ export function ProjectRetire(props: {
           fullWidth
           onChange={(e) => setForm({ ...form, tokenId: e.target.value })}
         />
+        <TextField
+          label="Beneficiary (Defaults to your account)"
+          name="beneficiary"
+          variant="standard"
+          fullWidth
+          onChange={(e) => setForm({ ...form, beneficiary: e.target.value })}
+          value={form.beneficiary}
+        />
+        <TextField
+          label="Reason"
+          name="reason"
+          variant="standard"
+          fullWidth
+          onChange={(e) => setForm({ ...form, reason: e.target.value })}
+          value={form.reason}
+        />
+        <TextField
+          label="Reporting Period Start"
+          name="reportingPeriodStart"
+          variant="standard"
+          fullWidth
+          type="datetime-local"
+          InputLabelProps={{ shrink: true }}
+          onChange={(e) => setForm({ ...form, reportingPeriodStart: e.target.value })}
+          value={form.reportingPeriodStart}
+        />
+        <TextField
+          label="Reporting Period End"
+          name="reportingPeriodEnd"
+          variant="standard"
+          fullWidth
+          type="datetime-local"
+          InputLabelProps={{ shrink: true }}
+          onChange={(e) => setForm({ ...form, reportingPeriodEnd: e.target.value })}
+          value={form.reportingPeriodEnd}
+        />
         <DisplayError error={state.error} />
         <Button type="submit" variant="contained" color="primary">
           Retire
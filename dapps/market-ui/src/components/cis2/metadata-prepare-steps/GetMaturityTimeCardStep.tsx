@@ -5,6 +5,7 @@ import {
     Button, Card, CardActions, CardContent, CardMedia, SxProps, TextField, Theme, Typography
 } from '@mui/material';
 
+import { ProjectInfo } from '../../../models/ProjectNFTClient';
 import DisplayError from '../../ui/DisplayError';
 
 const cardMediaSx: SxProps<Theme> = { maxHeight: "200px" };
@@ -12,10 +13,17 @@ const cardMediaSx: SxProps<Theme> = { maxHeight: "200px" };
 export default function GetMaturityTimeCardStep(props: {
   imageUrl?: string;
   tokenId: string;
-  onDone: (data: { tokenId: string; maturityTime: Date }) => void;
+  onDone: (data: { tokenId: string; maturityTime: Date; projectInfo: ProjectInfo }) => void;
 }) {
   const [form, setForm] = useState({
-    maturityTime: moment().add(1, "days").format("YYYY-MM-DDTHH:mm:ss")
+    maturityTime: moment().add(1, "days").format("YYYY-MM-DDTHH:mm:ss"),
+    vintage: moment().year().toString(),
+    registry: "",
+    serialRangeStart: "",
+    serialRangeEnd: "",
+    methodology: "",
+    country: "",
+    projectType: "",
   });
   const [state, setState] = useState({
     tokenId: props.tokenId.toString(),
@@ -30,10 +38,54 @@ export default function GetMaturityTimeCardStep(props: {
       return;
     }
 
+    const vintage = parseInt(form.vintage);
+    if (!vintage || vintage < 0 || vintage > 65535) {
+      setState({ ...state, error: "Invalid Vintage" });
+      return;
+    }
+
+    if (
+      !form.registry ||
+      !form.serialRangeStart ||
+      !form.serialRangeEnd ||
+      !form.methodology ||
+      !form.country ||
+      !form.projectType
+    ) {
+      setState({ ...state, error: "Please fill out all fields." });
+      return;
+    }
+
     setState({ ...state, error: "" });
-    props.onDone({ tokenId: props.tokenId, maturityTime: new Date(form.maturityTime) });
+    props.onDone({
+      tokenId: props.tokenId,
+      maturityTime: new Date(form.maturityTime),
+      projectInfo: {
+        vintage,
+        registry: form.registry,
+        serial_range: { start: form.serialRangeStart, end: form.serialRangeEnd },
+        methodology: form.methodology,
+        country: form.country,
+        project_type: form.projectType,
+      },
+    });
   }
 
+  const projectInfoField = (name: keyof typeof form, label: string) => (
+    <TextField
+      name={name}
+      id={name}
+      label={label}
+      variant="outlined"
+      size="small"
+      fullWidth={true}
+      required
+      margin="dense"
+      onChange={(e) => setForm({ ...form, [name]: e.target.value })}
+      value={form[name]}
+    />
+  );
+
   return (
     <Card variant="outlined">
       <CardMedia component="img" image={state.imageUrl} alt="NFT" sx={cardMediaSx} />
@@ -54,6 +106,16 @@ export default function GetMaturityTimeCardStep(props: {
             onChange={(e) => setForm({ ...form, maturityTime: e.target.value })}
             value={form.maturityTime}
           />
+          <Typography gutterBottom component="div" mt={2}>
+            Set Project Info
+          </Typography>
+          {projectInfoField("vintage", "Vintage")}
+          {projectInfoField("registry", "Registry")}
+          {projectInfoField("serialRangeStart", "Serial Range Start")}
+          {projectInfoField("serialRangeEnd", "Serial Range End")}
+          {projectInfoField("methodology", "Methodology")}
+          {projectInfoField("country", "Country (ISO 3166-1 alpha-2)")}
+          {projectInfoField("projectType", "Project Type")}
           <DisplayError error={state.error} />
         </CardContent>
         <CardActions>
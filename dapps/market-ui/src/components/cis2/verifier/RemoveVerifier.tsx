@@ -1,7 +1,7 @@
 import { useState } from 'react';
 
 import { ContractAddress, TransactionStatusEnum } from '@concordium/web-sdk';
-import { AlertColor, Button, Stack, TextField } from '@mui/material';
+import { AlertColor, Button, Checkbox, FormControlLabel, Stack, TextField } from '@mui/material';
 
 import { connectToWallet, ContractInfo } from '../../../models/ConcordiumContractClient';
 import { removeVerifier } from '../../../models/ProjectNFTClient';
@@ -15,6 +15,7 @@ export default function RemoveVerifier(props: {
 }) {
   const [form, setForm] = useState({
     verifier: "",
+    invalidateVerifications: false,
   });
   const [state, setState] = useState({
     loading: false,
@@ -45,6 +46,7 @@ export default function RemoveVerifier(props: {
           props.projectContract,
           props.contractInfo,
           form.verifier,
+          form.invalidateVerifications,
           BigInt(9999),
           (status, hash) => setTxn({ status, hash }),
         ),
@@ -72,6 +74,17 @@ export default function RemoveVerifier(props: {
         value={form.verifier}
         onChange={(event) => setForm({ ...form, verifier: event.target.value })}
       />
+      <FormControlLabel
+        label="Invalidate the verifications of the verifier"
+        control={
+          <Checkbox
+            id="invalidateVerifications"
+            name="invalidateVerifications"
+            checked={form.invalidateVerifications}
+            onChange={(event) => setForm({ ...form, invalidateVerifications: event.target.checked })}
+          />
+        }
+      />
       <DisplayError error={state.error} />
       {txn && <TransactionProgress status={txn.status} hash={txn.hash} />}
       {alert.open && (
@@ -15,6 +15,11 @@ export default function Verify(props: {
 }) {
   const [form, setForm] = useState({
     tokenId: "",
+    methodologyId: "",
+    reportingPeriodStart: "",
+    reportingPeriodEnd: "",
+    reportUrl: "",
+    reportHash: "",
   });
   const [state, setState] = useState({
     loading: false,
@@ -32,8 +37,16 @@ export default function Verify(props: {
 
   const onSubmit = (event: React.FormEvent<HTMLFormElement>) => {
     event.preventDefault();
-    if (!form.tokenId) {
+    if (
+      !form.tokenId ||
+      !form.methodologyId ||
+      !form.reportingPeriodStart ||
+      !form.reportingPeriodEnd ||
+      !form.reportUrl ||
+      !form.reportHash
+    ) {
       setState({ ...state, error: "Please fill out all fields." });
+      return;
     }
 
     setState({ ...state, loading: true, error: "" });
@@ -48,6 +61,15 @@ export default function Verify(props: {
           props.projectContract,
           props.contractInfo,
           form.tokenId,
+          {
+            methodology_id: form.methodologyId,
+            reporting_period: {
+              start: new Date(form.reportingPeriodStart).toISOString(),
+              end: new Date(form.reportingPeriodEnd).toISOString(),
+            },
+            report_url: form.reportUrl,
+            report_hash: form.reportHash,
+          },
           BigInt(9999),
           (status, hash) => setTxn({ status, hash }),
         ),
@@ -75,6 +97,50 @@ export default function Verify(props: {
         value={form.tokenId}
         onChange={(event) => setForm({ ...form, tokenId: event.target.value })}
       />
+      <TextField
+        label="Methodology Id"
+        variant="standard"
+        name="methodologyId"
+        id="methodologyId"
+        value={form.methodologyId}
+        onChange={(event) => setForm({ ...form, methodologyId: event.target.value })}
+      />
+      <TextField
+        label="Reporting Period Start"
+        variant="standard"
+        name="reportingPeriodStart"
+        id="reportingPeriodStart"
+        type="datetime-local"
+        InputLabelProps={{ shrink: true }}
+        value={form.reportingPeriodStart}
+        onChange={(event) => setForm({ ...form, reportingPeriodStart: event.target.value })}
+      />
+      <TextField
+        label="Reporting Period End"
+        variant="standard"
+        name="reportingPeriodEnd"
+        id="reportingPeriodEnd"
+        type="datetime-local"
+        InputLabelProps={{ shrink: true }}
+        value={form.reportingPeriodEnd}
+        onChange={(event) => setForm({ ...form, reportingPeriodEnd: event.target.value })}
+      />
+      <TextField
+        label="Report Url"
+        variant="standard"
+        name="reportUrl"
+        id="reportUrl"
+        value={form.reportUrl}
+        onChange={(event) => setForm({ ...form, reportUrl: event.target.value })}
+      />
+      <TextField
+        label="Report Hash (SHA-256 Hex)"
+        variant="standard"
+        name="reportHash"
+        id="reportHash"
+        value={form.reportHash}
+        onChange={(event) => setForm({ ...form, reportHash: event.target.value })}
+      />
       <DisplayError error={state.error} />
       {txn && <TransactionProgress status={txn.status} hash={txn.hash} />}
       {alert.open && (
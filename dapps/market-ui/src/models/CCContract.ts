@@ -5,11 +5,13 @@ import {
     deserializeTypeValue, InstanceInfo, serializeTypeValue
 } from '@concordium/web-sdk';
 
+import { MaturitySchedule } from './ProjectNFTClient';
+
 export default class CCContract extends CIS2Contract {
   private contractName2: string;
   private nodeClient: ConcordiumGRPCClient;
   private maturityOfParamSchema = "FAABAAAABwAAAHF1ZXJpZXMQAh0A";
-  private maturityOfReturnSchema = "EAIN";
+  private maturityOfReturnSchema = "EAIUAAEAAAAIAAAAdHJhbmNoZXMQAhQAAgAAAAQAAAB0aW1lDQgAAABmcmFjdGlvbgM=";
   private isVerifiedParamSchema = "FAABAAAABwAAAHF1ZXJpZXMQAh0A";
   private isVerifiedReturnSchema = "EAIB";
   private contractAddress2: ContractAddress;
@@ -40,6 +42,10 @@ export default class CCContract extends CIS2Contract {
     return contract;
   }
 
+  /**
+   * Gets the time at which all of the credits of a token have matured, i.e. the time of the last tranche of its
+   * maturity schedule.
+   */
   public async maturityOf(tokenId: string, invoker?: ContractAddress | AccountAddress): Promise<Date> {
     const methodName = "maturityOf";
     const parameter = serializeTypeValue({ queries: [tokenId] }, Buffer.from(this.maturityOfParamSchema, "base64"));
@@ -53,11 +59,12 @@ export default class CCContract extends CIS2Contract {
 
     switch (res.tag) {
       case "success": {
-        const retValueDe: string[] = deserializeTypeValue(
+        const retValueDe: MaturitySchedule[] = deserializeTypeValue(
           Buffer.from(res.returnValue || "", "hex"),
           Buffer.from(this.maturityOfReturnSchema, "base64"),
         ) as any;
-        return new Date(retValueDe[0]);
+        const { tranches } = retValueDe[0];
+        return new Date(tranches[tranches.length - 1].time);
       }
       case "failure":
         throw new Error(`Failed to invoke contract: ${res.reason}`);
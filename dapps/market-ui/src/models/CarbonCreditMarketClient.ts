@@ -159,6 +159,7 @@ export async function transfer({
     to,
     owner,
     quantity: quantity.toString(),
+    price: priceCcd.toString(),
   };
 
   return updateContract(
@@ -197,11 +198,21 @@ export interface OwnedTokenListItem {
   quantity: bigint;
 }
 
+/**
+ * Whether the token has been transferred to the Marketplace or the Marketplace is an operator of the seller.
+ */
+export type ListingMode = { Custody: [] } | { Operator: [] };
+
 export interface AddParams {
   cis_contract_address: ParamContractAddress;
   token_id: string;
   price: string;
   royalty: number;
+  /**
+   * Quantity of the token to be sold at this price.
+   */
+  quantity: string;
+  mode: ListingMode;
 }
 
 export interface TransferParams {
@@ -210,4 +221,8 @@ export interface TransferParams {
   to: string;
   owner: string;
   quantity: string;
+  /**
+   * Price per unit of the listing being bought.
+   */
+  price: string;
 }
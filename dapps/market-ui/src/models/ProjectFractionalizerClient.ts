@@ -2,7 +2,7 @@ import { SmartContractParameters, WalletApi } from '@concordium/browser-wallet-a
 import { ContractAddress, RejectReasonTag, TransactionStatusEnum } from '@concordium/web-sdk';
 
 import * as connClient from './ConcordiumContractClient';
-import { Attribute } from './ProjectNFTClient';
+import { Attribute, RetirementDetails } from './ProjectNFTClient';
 import { ModuleEvent } from './web/Events';
 import { getContractEventsByTransactionHash } from './web/WebClient';
 
@@ -31,6 +31,10 @@ export interface BurnParams {
   tokens: { token_id: string; amount: string }[];
 }
 
+export interface RetireParams extends BurnParams {
+  details: RetirementDetails;
+}
+
 /**
  * Adds a token to buyable list of tokens in marketplace.
  * @param provider Wallet Provider.
@@ -77,7 +81,7 @@ export async function retire(
   account: string,
   contractAddress: ContractAddress,
   contractInfo: connClient.ContractInfo,
-  params: BurnParams,
+  params: RetireParams,
   maxContractExecutionEnergy = BigInt(9999),
   onStatusUpdate: (status: TransactionStatusEnum, hash: string) => void = (status, hash) => console.log(status, hash),
 ) {
@@ -13,9 +13,48 @@ interface MintParams {
 interface MintParam {
   metadata_url: {
     url: string;
-    hash: { None: never[] } | { Some: [number[]] };
+    hash: { None: never[] } | { Some: [string] };
   };
-  maturity_time: string;
+  maturity: MaturitySchedule;
+  project_info: ProjectInfo;
+}
+
+/**
+ * Fractions of a maturity schedule are given in basis points, i.e. 10000 is 100%.
+ */
+export const MATURITY_BASIS_POINTS = 10000;
+
+/**
+ * Schedule by which the credits of a project mature. Tranches are ordered by time and their fractions add up to
+ * {@link MATURITY_BASIS_POINTS}.
+ */
+export interface MaturitySchedule {
+  tranches: { time: string; fraction: number }[];
+}
+
+/**
+ * Registry information of a carbon project, stored on chain when the project is minted.
+ */
+export interface ProjectInfo {
+  vintage: number;
+  registry: string;
+  serial_range: { start: string; end: string };
+  methodology: string;
+  country: string;
+  project_type: string;
+}
+
+/**
+ * Evidence recorded by a verifier when verifying a token.
+ */
+export interface Attestation {
+  methodology_id: string;
+  reporting_period: { start: string; end: string };
+  report_url: string;
+  /**
+   * Hex encoded SHA-256 hash of the verification report.
+   */
+  report_hash: string;
 }
 
 /**
@@ -45,6 +84,7 @@ export interface Attribute {
 export interface TokenInfo {
   metadataUrl: CIS2.MetadataUrl;
   maturityTime: Date;
+  projectInfo: ProjectInfo;
 }
 
 export interface BurnParams {
@@ -52,6 +92,22 @@ export interface BurnParams {
   tokens: { token_id: string; amount: string }[];
 }
 
+/**
+ * Details of a retirement, recorded in the retirement certificate.
+ */
+export interface RetirementDetails {
+  beneficiary: { Name: [string] } | { Address: [{ Account: [string] }] };
+  /**
+   * Reason for the retirement or the claim made with it.
+   */
+  reason: string;
+  reporting_period: { start: string; end: string };
+}
+
+export interface RetireParams extends BurnParams {
+  details: RetirementDetails;
+}
+
 
 /**
  * Mints multiple NFT in Contract: {@link nftContractAddress}
@@ -83,7 +139,11 @@ export async function mint(
             url: token.metadataUrl.url,
             hash: token.metadataUrl.hash ? { Some: [token.metadataUrl.hash] } : { None: [] },
           },
-          maturity_time: token.maturityTime.toISOString(),
+          // All of the credits mature at the maturity time.
+          maturity: {
+            tranches: [{ time: token.maturityTime.toISOString(), fraction: MATURITY_BASIS_POINTS }],
+          },
+          project_info: token.projectInfo,
         } as MintParam),
     ),
   } as MintParams;
@@ -108,7 +168,7 @@ export async function retire(
   account: string,
   contractAddress: ContractAddress,
   contractInfo: connClient.ContractInfo,
-  params: BurnParams,
+  params: RetireParams,
   maxContractExecutionEnergy = BigInt(9999),
   onStatusUpdate: (status: TransactionStatusEnum, hash: string) => void = (status, hash) => console.log(status, hash),
 ) {
@@ -198,6 +258,7 @@ export async function addVerifier(
  * @param nftContractAddress CIS-NFT contract address.
  * @param contractInfo Contract info.
  * @param verifier Verifier address.
+ * @param invalidateVerifications Whether to invalidate the verifications of the verifier or keep them.
  * @param maxContractExecutionEnergy Max allowed energy.
  * @returns Transaction outcomes {@link Record<string, TransactionSummary>}
  */
@@ -207,6 +268,7 @@ export async function removeVerifier(
   nftContractAddress: ContractAddress,
   contractInfo: connClient.ContractInfo,
   verifier: string,
+  invalidateVerifications: boolean,
   maxContractExecutionEnergy = BigInt(9999),
   onStatusUpdate: (status: TransactionStatusEnum, hash: string) => void = (status, hash) => console.log(status, hash),
 ) {
@@ -214,6 +276,7 @@ export async function removeVerifier(
     verifier: {
       Account: [verifier],
     },
+    invalidate_verifications: invalidateVerifications,
   };
 
   const outcomes = await connClient.updateContract(
@@ -238,6 +301,7 @@ export async function removeVerifier(
  * @param nftContractAddress CIS-NFT contract address.
  * @param contractInfo Contract info.
  * @param tokenId Token Id.
+ * @param attestation Evidence of the verification.
  * @param maxContractExecutionEnergy Max allowed energy.
  * @returns Transaction outcomes {@link Record<string, TransactionSummary>}
  */
@@ -247,11 +311,13 @@ export async function verify(
   nftContractAddress: ContractAddress,
   contractInfo: connClient.ContractInfo,
   tokenId: string,
+  attestation: Attestation,
   maxContractExecutionEnergy = BigInt(9999),
   onStatusUpdate: (status: TransactionStatusEnum, hash: string) => void = (status, hash) => console.log(status, hash),
 ) {
   const paramsJson = {
     token_id: tokenId,
+    attestation,
   };
 
   const outcomes = await connClient.updateContract(
@@ -31,6 +31,7 @@ export const transfer = async (
   tokenId: string,
   owner: string,
   quantity: bigint,
+  price: bigint,
   totalPaymentCcd: bigint,
   containerId = "widget",
   width = 400,
@@ -43,6 +44,7 @@ export const transfer = async (
       to: account,
       owner: owner,
       quantity: quantity.toString(),
+      price: price.toString(),
     };
     const parameter = serializeUpdateContractParameters(
       MARKETPLACE_CONTRACT_INFO.contractName,
@@ -24,7 +24,9 @@ export type Cis2TokenMetadataEvent = {
 
 export type ProjectNftMaturityTimeEvent = {
   token_id: string;
-  maturity_time: string;
+  maturity: {
+    tranches: { time: string; fraction: number }[];
+  };
 };
 
 export type Cis2BurnEvent = {
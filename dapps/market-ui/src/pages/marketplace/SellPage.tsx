@@ -92,7 +92,7 @@ function SellPage(props: {
                 address: marketContract,
                 hookName: "onCis2Recieved",
               }}
-              onDone={(address, tokenId, quantity) => onTransferred(address, tokenId, quantity)}
+              onDone={(address, tokenId, _, quantity) => onTransferred(address, tokenId, quantity)}
               defaultContractAddress={fromContract!}
             />
           </>
@@ -104,6 +104,7 @@ function SellPage(props: {
             marketContractAddress={marketContract}
             nftContractAddress={state.contractAddress!}
             tokenId={state.tokenId!}
+            quantity={state.quantity!}
             cis2Contract={state.cis2Contract!}
             onDone={() => onTokenListed()}
           />